@@ -18,11 +18,11 @@ pub fn to_rust_iterator(ob: PyObject, py: Python) -> PyResult<Vec<PyObject>> {
     // this changes the pointer's memory and is thus unsafe. In particular, `_export_to_c` can go out of bounds
     ob.call_method1(py, "_export_to_c", (stream_ptr as Py_uintptr_t,))?;
 
-    let mut iter =
+    let iter =
         unsafe { ffi::ArrowArrayStreamReader::try_new(stream).map_err(PyO3ArrowError::from) }?;
 
     let mut arrays = vec![];
-    while let Some(array) = unsafe { iter.next() } {
+    for array in iter {
         let py_array = to_py_array(array.unwrap().into(), py)?;
         arrays.push(py_array)
     }
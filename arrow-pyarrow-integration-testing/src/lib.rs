@@ -154,6 +154,46 @@ fn round_trip_field(array: PyObject, py: Python) -> PyResult<PyObject> {
     to_py_field(&field, py)
 }
 
+/// Converts a record batch to rust and back to python
+#[pyfunction]
+fn round_trip_record_batch(batch: PyObject, py: Python) -> PyResult<PyObject> {
+    // import
+    let array = Box::new(ffi::ArrowArray::empty());
+    let schema = Box::new(ffi::ArrowSchema::empty());
+
+    let array_ptr = &*array as *const ffi::ArrowArray;
+    let schema_ptr = &*schema as *const ffi::ArrowSchema;
+
+    batch.call_method1(
+        py,
+        "_export_to_c",
+        (array_ptr as Py_uintptr_t, schema_ptr as Py_uintptr_t),
+    )?;
+
+    let (fields, chunk) =
+        unsafe { ffi::import_chunk_from_c(array, schema.as_ref()).map_err(PyO3ArrowError::from)? };
+
+    // export
+    let array_ptr = Box::into_raw(Box::new(ffi::ArrowArray::empty()));
+    let schema_ptr = Box::into_raw(Box::new(ffi::ArrowSchema::empty()));
+
+    unsafe { ffi::export_chunk_to_c(chunk, &fields, array_ptr, schema_ptr) };
+
+    let pa = py.import("pyarrow")?;
+
+    let batch = pa.getattr("RecordBatch")?.call_method1(
+        "_import_from_c",
+        (array_ptr as Py_uintptr_t, schema_ptr as Py_uintptr_t),
+    )?;
+
+    unsafe {
+        Box::from_raw(array_ptr);
+        Box::from_raw(schema_ptr);
+    };
+
+    Ok(batch.to_object(py))
+}
+
 #[pyfunction]
 pub fn to_rust_iterator(ob: PyObject, py: Python) -> PyResult<Vec<PyObject>> {
     c_stream::to_rust_iterator(ob, py)
@@ -168,6 +208,7 @@ pub fn from_rust_iterator(py: Python) -> PyResult<PyObject> {
 fn arrow_pyarrow_integration_testing(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(round_trip_array, m)?)?;
     m.add_function(wrap_pyfunction!(round_trip_field, m)?)?;
+    m.add_function(wrap_pyfunction!(round_trip_record_batch, m)?)?;
     m.add_function(wrap_pyfunction!(to_rust_iterator, m)?)?;
     m.add_function(wrap_pyfunction!(from_rust_iterator, m)?)?;
     Ok(())
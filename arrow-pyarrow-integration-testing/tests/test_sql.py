@@ -116,6 +116,38 @@ class TestCase(unittest.TestCase):
         b = arrow_pyarrow_integration_testing.round_trip_array(a)
         self.assertEqual(a, b)
 
+    def test_decimal256_roundtrip(self):
+        """
+        Python -> Rust -> Python
+        """
+        data = [
+            round(decimal.Decimal(722.82), 2),
+            round(decimal.Decimal(-934.11), 2),
+            None,
+        ]
+        a = pyarrow.array(data, pyarrow.decimal256(5, 2))
+        b = arrow_pyarrow_integration_testing.round_trip_array(a)
+        b.validate(full=True)
+        self.assertEqual(a, b)
+        self.assertEqual(a.type, b.type)
+
+    def test_record_batch(self):
+        """
+        Python -> Rust -> Python
+        """
+        a = pyarrow.record_batch(
+            [
+                pyarrow.array([1, None, 3]),
+                pyarrow.array(["a", "b", None]),
+            ],
+            names=["a", "b"],
+        )
+        b = arrow_pyarrow_integration_testing.round_trip_record_batch(a)
+
+        b.validate(full=True)
+        assert a.schema == b.schema
+        assert a.to_pydict() == b.to_pydict()
+
     def test_list_array(self):
         """
         Python -> Rust -> Python
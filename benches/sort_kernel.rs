@@ -17,8 +17,11 @@
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use arrow2::array::ord::total_cmp_f32;
 use arrow2::array::*;
-use arrow2::compute::sort::{lexsort, sort, sort_to_indices, SortColumn, SortOptions};
+use arrow2::compute::sort::{
+    lexsort, sort, sort_owned_by, sort_to_indices, SortColumn, SortOptions,
+};
 use arrow2::util::bench_util::*;
 
 fn bench_lexsort(arr_a: &dyn Array, array_b: &dyn Array) {
@@ -49,6 +52,15 @@ fn bench_sort_limit(arr_a: &dyn Array) {
     .unwrap();
 }
 
+fn bench_sort_owned_limit(arr_a: PrimitiveArray<f32>) {
+    criterion::black_box(sort_owned_by(
+        arr_a,
+        total_cmp_f32,
+        &SortOptions::default(),
+        Some(100),
+    ));
+}
+
 fn add_benchmark(c: &mut Criterion) {
     (10..=20).step_by(2).for_each(|log2_size| {
         let size = 2usize.pow(log2_size);
@@ -62,6 +74,27 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| bench_sort_limit(&arr_a))
         });
 
+        // compare sorting a copy of the values against sorting them in place
+        c.bench_function(&format!("sort-values-limit 2^{} f32", log2_size), |b| {
+            b.iter(|| {
+                sort(
+                    criterion::black_box(&arr_a),
+                    &SortOptions::default(),
+                    Some(100),
+                )
+            })
+        });
+        c.bench_function(
+            &format!("sort-owned-values-limit 2^{} f32", log2_size),
+            |b| {
+                b.iter_batched(
+                    || PrimitiveArray::<f32>::from_slice(arr_a.values().as_slice()),
+                    bench_sort_owned_limit,
+                    criterion::BatchSize::LargeInput,
+                )
+            },
+        );
+
         let arr_b = create_primitive_array_with_seed::<f32>(size, 0.0, 43);
         c.bench_function(&format!("lexsort 2^{} f32", log2_size), |b| {
             b.iter(|| bench_lexsort(&arr_a, &arr_b))
@@ -1,7 +1,10 @@
 use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
-    array::{specification::check_offsets, Array, MutableArray, Offset, TryExtend, TryPush},
+    array::{
+        downcast_with_type, specification::check_offsets, Array, MutableArray, Offset, TryExtend,
+        TryPush,
+    },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -229,6 +232,16 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
         self.push::<&[u8]>(None)
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn extend_from_array(&mut self, other: &dyn Array) -> Result<()> {
+        let other = downcast_with_type::<BinaryArray<O>>(self.data_type(), other)?;
+        self.extend_trusted_len(other.iter());
+        Ok(())
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
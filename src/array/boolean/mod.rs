@@ -201,6 +201,31 @@ impl BooleanArray {
     pub fn values(&self) -> &Bitmap {
         &self.values
     }
+
+    /// Returns the number of non-null values that are `true`.
+    /// # Implementation
+    /// This is `O(1)` when the array has no validity and `O(N)` otherwise,
+    /// since the nulls are masked out of the values before counting.
+    pub fn count_true(&self) -> usize {
+        if let Some(validity) = &self.validity {
+            (&self.values & validity).set_bits()
+        } else {
+            self.values.set_bits()
+        }
+    }
+
+    /// Returns the number of non-null values that are `false`.
+    /// # Implementation
+    /// This is `O(1)` when the array has no validity and `O(N)` otherwise.
+    pub fn count_false(&self) -> usize {
+        self.len() - self.null_count() - self.count_true()
+    }
+
+    /// Returns the number of null values. Alias of [`Array::null_count`].
+    #[inline]
+    pub fn count_null(&self) -> usize {
+        self.null_count()
+    }
 }
 
 impl Array for BooleanArray {
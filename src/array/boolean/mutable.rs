@@ -2,7 +2,7 @@ use std::iter::FromIterator;
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{downcast_with_type, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::{DataType, PhysicalType},
     error::Result,
@@ -495,6 +495,16 @@ impl MutableArray for MutableBooleanArray {
         self.push(None)
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn extend_from_array(&mut self, other: &dyn Array) -> Result<()> {
+        let other = downcast_with_type::<BooleanArray>(self.data_type(), other)?;
+        self.extend_trusted_len(other.iter());
+        Ok(())
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
@@ -89,6 +89,26 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
+    /// Returns a new [`DictionaryArray`] with a different logical type, e.g. an ordered
+    /// dictionary or an extension type.
+    ///
+    /// This is `O(1)`.
+    /// # Panics
+    /// Panics iff `data_type`'s logical type is not [`DataType::Dictionary`] with keys `K`
+    /// and values of this array's values' [`DataType`].
+    pub fn to(self, data_type: DataType) -> Self {
+        match data_type.to_logical_type() {
+            DataType::Dictionary(key, values, _)
+                if *key == K::KEY_TYPE && values.as_ref() == self.values.data_type() => {}
+            _ => panic!("Wrong DataType"),
+        }
+        Self {
+            data_type,
+            keys: self.keys,
+            values: self.values,
+        }
+    }
+
     /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
     /// # Panics
     /// iff `offset + length > self.len()`.
@@ -192,12 +192,8 @@ impl FixedSizeBinaryArray {
     /// Panics iff the data_type is not supported for the physical type.
     #[inline]
     pub fn to(self, data_type: DataType) -> Self {
-        match (
-            data_type.to_logical_type(),
-            self.data_type().to_logical_type(),
-        ) {
-            (DataType::FixedSizeBinary(size_a), DataType::FixedSizeBinary(size_b))
-                if size_a == size_b => {}
+        match Self::maybe_get_size(&data_type) {
+            Ok(size) if size == self.size => {}
             _ => panic!("Wrong DataType"),
         }
 
@@ -219,8 +215,9 @@ impl FixedSizeBinaryArray {
     pub(crate) fn maybe_get_size(data_type: &DataType) -> Result<usize, ArrowError> {
         match data_type.to_logical_type() {
             DataType::FixedSizeBinary(size) => Ok(*size),
+            DataType::Decimal256(_, _) => Ok(32),
             _ => Err(ArrowError::oos(
-                "FixedSizeBinaryArray expects DataType::FixedSizeBinary",
+                "FixedSizeBinaryArray expects DataType::FixedSizeBinary or DataType::Decimal256",
             )),
         }
     }
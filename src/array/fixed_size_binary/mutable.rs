@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray},
+    array::{downcast_with_type, Array, MutableArray},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -175,6 +175,14 @@ impl MutableFixedSizeBinaryArray {
         std::slice::from_raw_parts(self.values.as_ptr().add(i * self.size), self.size)
     }
 
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional * self.size);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableFixedSizeBinaryArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -238,6 +246,17 @@ impl MutableArray for MutableFixedSizeBinaryArray {
         self.values.resize(self.values.len() + self.size, 0);
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn extend_from_array(&mut self, other: &dyn Array) -> Result<()> {
+        let other = downcast_with_type::<FixedSizeBinaryArray>(self.data_type(), other)?;
+        self.reserve(other.len());
+        other.iter().for_each(|x| self.push(x));
+        Ok(())
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
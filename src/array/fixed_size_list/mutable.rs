@@ -41,6 +41,34 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
         }
     }
 
+    /// Creates a new [`MutableFixedSizeListArray`] from a [`DataType`] and an empty [`MutableArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s physical type is not [`crate::datatypes::PhysicalType::FixedSizeList`]
+    /// * the data type of `values` is different from the child's data type in `data_type`
+    /// * `values` is not empty
+    pub fn try_new(data_type: DataType, values: M) -> Result<Self> {
+        let (child, size) = FixedSizeListArray::try_child_and_size(&data_type)?;
+        if child.data_type() != values.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The values of a MutableFixedSizeListArray must have the child data type {:?} but they have {:?}",
+                child.data_type(),
+                values.data_type()
+            )));
+        }
+        if !values.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "The values of a new MutableFixedSizeListArray must be empty".to_string(),
+            ));
+        }
+        Ok(Self {
+            size,
+            data_type,
+            values,
+            validity: None,
+        })
+    }
+
     /// Creates a new [`MutableFixedSizeListArray`] from a [`MutableArray`] and size.
     pub fn new_with_field(values: M, name: &str, nullable: bool, size: usize) -> Self {
         let data_type = DataType::FixedSizeList(
@@ -88,14 +116,23 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
         Ok(())
     }
 
+    /// Pushes a null entry, pushing `size` nulls to the values.
     #[inline]
-    fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         (0..self.size).for_each(|_| self.values.push_null());
         match &mut self.validity {
             Some(validity) => validity.push(false),
             None => self.init_validity(),
         }
     }
+    /// Reserves `additional` slots, i.e. `additional * size` slots of its values.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional * self.size);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableFixedSizeListArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -144,14 +181,11 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
 
     #[inline]
     fn push_null(&mut self) {
-        (0..self.size).for_each(|_| {
-            self.values.push_null();
-        });
-        if let Some(validity) = &mut self.validity {
-            validity.push(false)
-        } else {
-            self.init_validity()
-        }
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
     }
 
     fn shrink_to_fit(&mut self) {
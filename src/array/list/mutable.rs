@@ -189,6 +189,17 @@ impl<O: Offset, M: MutableArray> MutableListArray<O, M> {
         Box::new(a)
     }
 
+    /// Reserves `additional` slots.
+    ///
+    /// The child values are not reserved, since the length of the new lists is not known
+    /// in advance; use [`Self::mut_values`] to reserve them.
+    pub fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
     /// Shrinks the capacity of the [`MutableListArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -242,6 +253,11 @@ impl<O: Offset, M: MutableArray + Default + 'static> MutableArray for MutableLis
     fn push_null(&mut self) {
         self.push_null()
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit();
     }
@@ -18,7 +18,7 @@
 //! can be operated in-place.
 use std::any::Any;
 
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{
     bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
@@ -158,6 +158,23 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
             .unwrap_or(true)
     }
 
+    /// Reserves additional slots to its capacity.
+    /// Nested arrays whose children's lengths are not known in advance (e.g. list, dense union
+    /// and dictionary values) only reserve their own slots.
+    /// The default implementation does nothing.
+    fn reserve(&mut self, _additional: usize) {}
+
+    /// Extends the array with all values of `other`, including its nulls.
+    /// # Errors
+    /// This function errors iff `other`'s [`DataType`] differs from this array's [`DataType`]
+    /// or when this operation is not implemented for this array.
+    fn extend_from_array(&mut self, other: &dyn Array) -> Result<()> {
+        Err(ArrowError::NotYetImplemented(format!(
+            "Extending a mutable array of data type {:?} from an array",
+            self.data_type()
+        )))
+    }
+
     /// Shrink the array to fit its length.
     fn shrink_to_fit(&mut self);
 }
@@ -341,6 +358,21 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
     }
 }
 
+/// Downcasts `other` to `A`, erroring if its [`DataType`] is not `data_type`.
+pub(crate) fn downcast_with_type<'a, A: 'static>(
+    data_type: &DataType,
+    other: &'a dyn Array,
+) -> Result<&'a A> {
+    if other.data_type() != data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "An array of data type {:?} can't extend a mutable array of data type {:?}",
+            other.data_type(),
+            data_type
+        )));
+    }
+    Ok(other.as_any().downcast_ref::<A>().unwrap())
+}
+
 // see https://users.rust-lang.org/t/generic-for-dyn-a-or-box-dyn-a-or-arc-dyn-a/69430/3
 // for details
 impl<'a> AsRef<(dyn Array + 'a)> for dyn Array {
@@ -382,8 +414,8 @@ pub use list::{ListArray, ListValuesIter, MutableListArray};
 pub use map::MapArray;
 pub use null::NullArray;
 pub use primitive::*;
-pub use struct_::StructArray;
-pub use union::UnionArray;
+pub use struct_::{MutableStructArray, StructArray};
+pub use union::{MutableUnionArray, UnionArray};
 pub use utf8::{MutableUtf8Array, Utf8Array, Utf8ValuesIter};
 
 pub(crate) use self::ffi::offset_buffers_children_dictionary;
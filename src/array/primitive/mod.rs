@@ -212,6 +212,17 @@ impl<T: NativeType> PrimitiveArray<T> {
             validity: self.validity,
         }
     }
+    /// Returns its internal representation
+    #[must_use]
+    pub fn into_data(self) -> (DataType, Buffer<T>, Option<Bitmap>) {
+        let Self {
+            data_type,
+            values,
+            validity,
+        } = self;
+        (data_type, values, validity)
+    }
+
     /// Try to convert this `PrimitiveArray` to a `MutablePrimitiveArray`
     pub fn into_mut(self) -> Either<Self, MutablePrimitiveArray<T>> {
         use Either::*;
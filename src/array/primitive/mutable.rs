@@ -1,7 +1,7 @@
 use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{downcast_with_type, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -410,6 +410,16 @@ impl<T: NativeType> MutableArray for MutablePrimitiveArray<T> {
         self.push(None)
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn extend_from_array(&mut self, other: &dyn Array) -> Result<()> {
+        let other = downcast_with_type::<PrimitiveArray<T>>(self.data_type(), other)?;
+        self.extend_trusted_len(other.iter());
+        Ok(())
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
@@ -11,6 +11,9 @@ use super::{new_empty_array, new_null_array, Array};
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
+mod mutable;
+pub(crate) use mutable::push_scalar;
+pub use mutable::MutableStructArray;
 
 /// A [`StructArray`] is a nested [`Array`] with an optional validity representing
 /// multiple [`Array`] with the same number of rows.
@@ -123,6 +126,18 @@ impl StructArray {
         Self::try_new(data_type, values, validity).unwrap()
     }
 
+    /// Returns a new [`StructArray`] of `DataType::Struct(fields)` from its `columns`.
+    /// # Errors
+    /// This function errors under the same conditions as [`StructArray::try_new`], e.g. when
+    /// the number of `fields` and `columns` differ or when the columns have different lengths.
+    pub fn try_from_columns(
+        fields: Vec<Field>,
+        columns: Vec<Arc<dyn Array>>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, ArrowError> {
+        Self::try_new(DataType::Struct(fields), columns, validity)
+    }
+
     /// Alias for `new`
     pub fn from_data(
         data_type: DataType,
@@ -0,0 +1,403 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        Array, MutableArray, MutableBinaryArray, MutableBooleanArray, MutableFixedSizeBinaryArray,
+        MutablePrimitiveArray, MutableUtf8Array,
+    },
+    bitmap::MutableBitmap,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+    scalar::{
+        BinaryScalar, BooleanScalar, FixedSizeBinaryScalar, NullScalar, PrimitiveScalar, Scalar,
+        StructScalar, Utf8Scalar,
+    },
+};
+
+use super::StructArray;
+
+/// The mutable version of [`StructArray`], whose children are [`MutableArray`]s.
+/// # Example
+/// ```
+/// use arrow2::array::*;
+/// use arrow2::datatypes::{DataType, Field};
+/// use arrow2::scalar::{PrimitiveScalar, Scalar, Utf8Scalar};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let data_type = DataType::Struct(vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ]);
+/// let values: Vec<Box<dyn MutableArray>> = vec![
+///     Box::new(MutablePrimitiveArray::<i32>::new()),
+///     Box::new(MutableUtf8Array::<i32>::new()),
+/// ];
+/// let mut array = MutableStructArray::try_new(data_type, values, None)?;
+///
+/// array.push_value(vec![
+///     Box::new(PrimitiveScalar::from(Some(1i32))) as Box<dyn Scalar>,
+///     Box::new(Utf8Scalar::<i32>::new(Some("a"))),
+/// ])?;
+/// array.push_null();
+///
+/// let array: StructArray = array.into();
+/// assert_eq!(array.len(), 2);
+/// assert!(array.is_null(1));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MutableStructArray {
+    data_type: DataType,
+    values: Vec<Box<dyn MutableArray>>,
+    validity: Option<MutableBitmap>,
+}
+
+impl From<MutableStructArray> for StructArray {
+    fn from(other: MutableStructArray) -> Self {
+        StructArray::new(
+            other.data_type,
+            other.values.into_iter().map(|mut x| x.as_arc()).collect(),
+            other.validity.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableStructArray {
+    /// Returns a new [`MutableStructArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Struct`].
+    /// * the children of `data_type` are empty
+    /// * the values's len is different from children's length
+    /// * any of the values's data type is different from its corresponding children' data type
+    /// * any element of values has a different length than the first element
+    /// * the validity's length is not equal to the length of the first element
+    pub fn try_new(
+        data_type: DataType,
+        values: Vec<Box<dyn MutableArray>>,
+        validity: Option<MutableBitmap>,
+    ) -> Result<Self> {
+        let fields = StructArray::try_get_fields(&data_type)?;
+        if fields.is_empty() {
+            return Err(ArrowError::oos(
+                "A MutableStructArray must contain at least one field",
+            ));
+        }
+        if fields.len() != values.len() {
+            return Err(ArrowError::oos(
+                "A MutableStructArray must a number of fields in its DataType equal to the number of child values",
+            ));
+        }
+        if fields
+            .iter()
+            .zip(values.iter())
+            .any(|(field, value)| field.data_type() != value.data_type())
+        {
+            return Err(ArrowError::oos(
+                "The children DataTypes of a MutableStructArray must equal the children data types",
+            ));
+        }
+
+        let len = values[0].len();
+        if values.iter().any(|value| value.len() != len) {
+            return Err(ArrowError::oos(
+                "The children of a MutableStructArray must have the same length",
+            ));
+        }
+        if validity
+            .as_ref()
+            .map_or(false, |validity| validity.len() != len)
+        {
+            return Err(ArrowError::oos(
+                "The validity length of a MutableStructArray must match its number of elements",
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            values,
+            validity,
+        })
+    }
+
+    /// Returns a new [`MutableStructArray`].
+    /// # Panics
+    /// This function panics iff [`MutableStructArray::try_new`] errors.
+    pub fn new(
+        data_type: DataType,
+        values: Vec<Box<dyn MutableArray>>,
+        validity: Option<MutableBitmap>,
+    ) -> Self {
+        Self::try_new(data_type, values, validity).unwrap()
+    }
+
+    /// The inner values
+    pub fn values(&self) -> &[Box<dyn MutableArray>] {
+        &self.values
+    }
+
+    /// The values as a mutable reference
+    pub fn mut_values(&mut self) -> &mut [Box<dyn MutableArray>] {
+        &mut self.values
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.len();
+
+        let mut validity = MutableBitmap::new();
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Pushes a new valid entry whose children are `values`.
+    /// # Errors
+    /// This function errors iff:
+    /// * the number of `values` differs from the number of children
+    /// * any of the `values`'s data type differs from its corresponding child's data type
+    /// * pushing a scalar of a given type to its child is not implemented
+    pub fn push_value(&mut self, values: Vec<Box<dyn Scalar>>) -> Result<()> {
+        self.try_push_scalars(values.iter().map(|x| x.as_ref()).collect())
+    }
+
+    fn try_push_scalars(&mut self, values: Vec<&dyn Scalar>) -> Result<()> {
+        if values.len() != self.values.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A MutableStructArray with {} children can't be pushed {} values",
+                self.values.len(),
+                values.len()
+            )));
+        }
+        // check all values upfront so that a failed push does not leave the children with different lengths
+        self.values
+            .iter()
+            .zip(values.iter())
+            .try_for_each(|(child, value)| check_scalar(child.as_ref(), *value))?;
+
+        self.values
+            .iter_mut()
+            .zip(values)
+            .try_for_each(|(child, value)| push_scalar(child.as_mut(), value))?;
+
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    /// Pushes a new null entry. Each child receives a null entry.
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.values.iter_mut().for_each(|x| x.push_null());
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    /// Reserves `additional` slots in each of its children and in its validity.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.iter_mut().for_each(|x| x.reserve(additional));
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableStructArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.values.iter_mut().for_each(|x| x.shrink_to_fit());
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+}
+
+fn downcast_mut<T: 'static>(array: &mut dyn MutableArray) -> Result<&mut T> {
+    let data_type = array.data_type().clone();
+    array.as_mut_any().downcast_mut::<T>().ok_or_else(|| {
+        ArrowError::NotYetImplemented(format!(
+            "Pushing scalars of data type {:?} requires the canonical mutable array of that type",
+            data_type
+        ))
+    })
+}
+
+macro_rules! push_dyn {
+    ($array:expr, $ty:ty, $scalar:expr, $scalar_ty:ty) => {{
+        let scalar = $scalar.as_any().downcast_ref::<$scalar_ty>().unwrap();
+        downcast_mut::<$ty>($array)?.push(scalar.value())
+    }};
+}
+
+fn check_downcast<T: 'static>(array: &dyn MutableArray) -> Result<()> {
+    if array.as_any().is::<T>() {
+        Ok(())
+    } else {
+        Err(ArrowError::NotYetImplemented(format!(
+            "Pushing scalars of data type {:?} requires the canonical mutable array of that type",
+            array.data_type()
+        )))
+    }
+}
+
+/// Checks that [`push_scalar`] would succeed, without mutating `array`.
+fn check_scalar(array: &dyn MutableArray, scalar: &dyn Scalar) -> Result<()> {
+    if array.data_type() != scalar.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A value of data type {:?} can't be pushed to a child of data type {:?}",
+            scalar.data_type(),
+            array.data_type()
+        )));
+    }
+    if scalar.as_any().is::<NullScalar>() {
+        return Ok(());
+    }
+    use PhysicalType::*;
+    match scalar.data_type().to_physical_type() {
+        Boolean => check_downcast::<MutableBooleanArray>(array),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            check_downcast::<MutablePrimitiveArray<$T>>(array)
+        }),
+        Utf8 => check_downcast::<MutableUtf8Array<i32>>(array),
+        LargeUtf8 => check_downcast::<MutableUtf8Array<i64>>(array),
+        Binary => check_downcast::<MutableBinaryArray<i32>>(array),
+        LargeBinary => check_downcast::<MutableBinaryArray<i64>>(array),
+        FixedSizeBinary => {
+            check_downcast::<MutableFixedSizeBinaryArray>(array)?;
+            let size = array
+                .as_any()
+                .downcast_ref::<MutableFixedSizeBinaryArray>()
+                .unwrap()
+                .size();
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            match scalar.value() {
+                Some(value) if value.len() != size => Err(ArrowError::InvalidArgumentError(
+                    "FixedSizeBinaryArray requires every item to be of its length".to_string(),
+                )),
+                _ => Ok(()),
+            }
+        }
+        Struct => {
+            check_downcast::<MutableStructArray>(array)?;
+            let array = array.as_any().downcast_ref::<MutableStructArray>().unwrap();
+            let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+            if !scalar.is_valid() {
+                return Ok(());
+            }
+            if scalar.values().len() != array.values.len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "A MutableStructArray with {} children can't be pushed {} values",
+                    array.values.len(),
+                    scalar.values().len()
+                )));
+            }
+            array
+                .values
+                .iter()
+                .zip(scalar.values().iter())
+                .try_for_each(|(child, value)| check_scalar(child.as_ref(), value.as_ref()))
+        }
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Pushing scalars of physical type {:?} to a mutable array",
+            other
+        ))),
+    }
+}
+
+/// Pushes `scalar` to `array`, assuming that both have the same [`DataType`].
+pub(crate) fn push_scalar(array: &mut dyn MutableArray, scalar: &dyn Scalar) -> Result<()> {
+    // a `NullScalar` may be of any type
+    if scalar.as_any().is::<NullScalar>() {
+        array.push_null();
+        return Ok(());
+    }
+    use PhysicalType::*;
+    match scalar.data_type().to_physical_type() {
+        Boolean => push_dyn!(array, MutableBooleanArray, scalar, BooleanScalar),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            push_dyn!(array, MutablePrimitiveArray<$T>, scalar, PrimitiveScalar<$T>)
+        }),
+        Utf8 => push_dyn!(array, MutableUtf8Array<i32>, scalar, Utf8Scalar<i32>),
+        LargeUtf8 => push_dyn!(array, MutableUtf8Array<i64>, scalar, Utf8Scalar<i64>),
+        Binary => push_dyn!(array, MutableBinaryArray<i32>, scalar, BinaryScalar<i32>),
+        LargeBinary => push_dyn!(array, MutableBinaryArray<i64>, scalar, BinaryScalar<i64>),
+        FixedSizeBinary => {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            downcast_mut::<MutableFixedSizeBinaryArray>(array)?.try_push(scalar.value())?
+        }
+        Struct => {
+            let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+            let array = downcast_mut::<MutableStructArray>(array)?;
+            if scalar.is_valid() {
+                array.try_push_scalars(scalar.values().iter().map(|x| x.as_ref()).collect())?
+            } else {
+                array.push_null()
+            }
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Pushing scalars of physical type {:?} to a mutable array",
+                other
+            )))
+        }
+    };
+    Ok(())
+}
+
+impl MutableArray for MutableStructArray {
+    fn len(&self) -> usize {
+        self.values[0].len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(StructArray::new(
+            self.data_type.clone(),
+            self.values.iter_mut().map(|x| x.as_arc()).collect(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(StructArray::new(
+            self.data_type.clone(),
+            self.values.iter_mut().map(|x| x.as_arc()).collect(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
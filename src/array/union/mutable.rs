@@ -0,0 +1,281 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{struct_::push_scalar, Array, MutableArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    scalar::Scalar,
+};
+
+use super::UnionArray;
+
+/// The mutable version of [`UnionArray`], whose children are [`MutableArray`]s.
+/// It supports both sparse and dense unions.
+/// # Example
+/// ```
+/// use arrow2::array::*;
+/// use arrow2::datatypes::{DataType, Field, UnionMode};
+/// use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let fields = vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ];
+/// let data_type = DataType::Union(fields, None, UnionMode::Dense);
+/// let values: Vec<Box<dyn MutableArray>> = vec![
+///     Box::new(MutablePrimitiveArray::<i32>::new()),
+///     Box::new(MutableUtf8Array::<i32>::new()),
+/// ];
+/// let mut array = MutableUnionArray::try_new(data_type, values)?;
+///
+/// array.push(0, Box::new(PrimitiveScalar::from(Some(1i32))))?;
+/// array.push(1, Box::new(Utf8Scalar::<i32>::new(Some("a"))))?;
+///
+/// let array: UnionArray = array.into();
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(array.offsets().unwrap().as_slice(), &[0, 0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MutableUnionArray {
+    data_type: DataType,
+    types: Vec<i8>,
+    fields: Vec<Box<dyn MutableArray>>,
+    // `None` when the union is sparse
+    offsets: Option<Vec<i32>>,
+}
+
+impl From<MutableUnionArray> for UnionArray {
+    fn from(other: MutableUnionArray) -> Self {
+        UnionArray::new(
+            other.data_type,
+            other.types.into(),
+            other.fields.into_iter().map(|mut x| x.as_arc()).collect(),
+            other.offsets.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableUnionArray {
+    /// Returns a new empty [`MutableUnionArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Union`].
+    /// * the fields's len is different from the `data_type`'s children's length
+    /// * any of the fields's data type is different from its corresponding children' data type
+    /// * any of the fields is not empty
+    pub fn try_new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Result<Self> {
+        let (f, _, mode) = UnionArray::try_get_all(&data_type)?;
+
+        if f.len() != fields.len() {
+            return Err(ArrowError::oos(
+                "The number of `fields` must equal the number of children fields in DataType::Union",
+            ));
+        };
+        if f.iter()
+            .zip(fields.iter())
+            .any(|(f, field)| f.data_type() != field.data_type())
+        {
+            return Err(ArrowError::oos(
+                "The children DataTypes of a MutableUnionArray must equal the children data types",
+            ));
+        }
+        if fields.iter().any(|x| !x.is_empty()) {
+            return Err(ArrowError::oos(
+                "The fields of a new MutableUnionArray must be empty",
+            ));
+        }
+
+        let offsets = if mode.is_sparse() { None } else { Some(vec![]) };
+
+        Ok(Self {
+            data_type,
+            types: vec![],
+            fields,
+            offsets,
+        })
+    }
+
+    /// The types of this array
+    pub fn types(&self) -> &[i8] {
+        &self.types
+    }
+
+    /// The fields of this array
+    pub fn fields(&self) -> &[Box<dyn MutableArray>] {
+        &self.fields
+    }
+
+    /// The offsets of this array, when it is dense
+    pub fn offsets(&self) -> Option<&[i32]> {
+        self.offsets.as_deref()
+    }
+
+    /// Returns the index of the field of `type_id`
+    fn field_index(&self, type_id: i8) -> Result<usize> {
+        let (_, ids, _) = UnionArray::get_all(&self.data_type);
+        let index = match ids {
+            Some(ids) => ids.iter().position(|x| *x == type_id as i32),
+            None => Some(type_id as usize).filter(|x| *x < self.fields.len()),
+        };
+        index.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The type id {} does not correspond to any field of the union",
+                type_id
+            ))
+        })
+    }
+
+    /// Pushes `value` to the field of `type_id`.
+    /// When the union is sparse, every other field receives a null entry.
+    /// # Errors
+    /// This function errors iff:
+    /// * `type_id` does not correspond to any field
+    /// * `value`'s data type is different from the field's data type
+    /// * pushing a scalar of a given type to its field is not implemented
+    /// * the union is dense and the field has more than `i32::MAX` entries
+    pub fn push(&mut self, type_id: i8, value: Box<dyn Scalar>) -> Result<()> {
+        let index = self.field_index(type_id)?;
+
+        let field = &self.fields[index];
+        if field.data_type() != value.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A value of data type {:?} can't be pushed to a field of data type {:?}",
+                value.data_type(),
+                field.data_type()
+            )));
+        }
+
+        let offset = self.next_offset(index)?;
+        push_scalar(self.fields[index].as_mut(), value.as_ref())?;
+        self.push_type(index, type_id, offset);
+        Ok(())
+    }
+
+    /// Returns the offset of the next entry of the field `index` (always 0 when the union is sparse).
+    fn next_offset(&self, index: usize) -> Result<i32> {
+        if self.offsets.is_none() {
+            return Ok(0);
+        }
+        i32::try_from(self.fields[index].len()).map_err(|_| ArrowError::Overflow)
+    }
+
+    fn push_type(&mut self, index: usize, type_id: i8, offset: i32) {
+        match &mut self.offsets {
+            Some(offsets) => offsets.push(offset),
+            None => self
+                .fields
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .for_each(|(_, field)| field.push_null()),
+        }
+        self.types.push(type_id);
+    }
+
+    /// Pushes a null entry to the first field of the union.
+    /// # Errors
+    /// This function errors iff:
+    /// * the union has no fields
+    /// * the union is dense and the first field has more than `i32::MAX` entries
+    pub fn try_push_null(&mut self) -> Result<()> {
+        if self.fields.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A union without fields can't hold a null entry".to_string(),
+            ));
+        }
+        let (_, ids, _) = UnionArray::get_all(&self.data_type);
+        let type_id = ids.map(|ids| ids[0] as i8).unwrap_or(0);
+
+        let offset = self.next_offset(0)?;
+        self.fields[0].push_null();
+        self.push_type(0, type_id, offset);
+        Ok(())
+    }
+
+    /// Pushes a null entry to the first field of the union.
+    /// # Panics
+    /// This function panics iff [`MutableUnionArray::try_push_null`] errors.
+    pub fn push_null(&mut self) {
+        self.try_push_null().unwrap()
+    }
+
+    /// Reserves `additional` slots.
+    ///
+    /// When the union is sparse, every field is reserved by `additional` slots.
+    /// When it is dense, only the types and offsets are reserved, since it is not known
+    /// in advance to which fields the new slots belong.
+    pub fn reserve(&mut self, additional: usize) {
+        self.types.reserve(additional);
+        if let Some(offsets) = &mut self.offsets {
+            offsets.reserve(additional)
+        } else {
+            self.fields.iter_mut().for_each(|x| x.reserve(additional));
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableUnionArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        self.fields.iter_mut().for_each(|x| x.shrink_to_fit());
+        if let Some(offsets) = &mut self.offsets {
+            offsets.shrink_to_fit()
+        }
+    }
+}
+
+impl MutableArray for MutableUnionArray {
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            self.fields.iter_mut().map(|x| x.as_arc()).collect(),
+            self.offsets.as_mut().map(|x| std::mem::take(x).into()),
+        ))
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            self.fields.iter_mut().map(|x| x.as_arc()).collect(),
+            self.offsets.as_mut().map(|x| std::mem::take(x).into()),
+        ))
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
@@ -2,6 +2,7 @@ use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
     array::{
+        downcast_with_type,
         specification::{check_offsets_and_utf8, check_offsets_minimal},
         Array, MutableArray, Offset, TryExtend, TryPush,
     },
@@ -275,6 +276,16 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
         self.push::<&str>(None)
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional, 0)
+    }
+
+    fn extend_from_array(&mut self, other: &dyn Array) -> Result<()> {
+        let other = downcast_with_type::<Utf8Array<O>>(self.data_type(), other)?;
+        self.extend_trusted_len(other.iter());
+        Ok(())
+    }
+
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
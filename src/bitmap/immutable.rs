@@ -108,6 +108,22 @@ impl Bitmap {
         self.null_count
     }
 
+    /// Returns the number of set bits on this [`Bitmap`].
+    /// # Implementation
+    /// This is `O(1)`, since the number of unset bits is cached.
+    #[inline]
+    pub fn set_bits(&self) -> usize {
+        self.length - self.null_count
+    }
+
+    /// Returns the number of unset bits on this [`Bitmap`]. Alias of [`Bitmap::null_count`].
+    /// # Implementation
+    /// This is `O(1)`, since the number of unset bits is cached.
+    #[inline]
+    pub fn unset_bits(&self) -> usize {
+        self.null_count
+    }
+
     /// Slices `self`, offsetting by `offset` and truncating up to `length` bits.
     /// # Panic
     /// Panics iff `self.offset + offset + length >= self.bytes.len() * 8`, i.e. if the offset and `length`
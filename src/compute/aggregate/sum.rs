@@ -1,6 +1,7 @@
 use std::ops::Add;
 
 use multiversion::multiversion;
+use num_traits::AsPrimitive;
 
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
@@ -104,6 +105,19 @@ where
     }
 }
 
+/// Returns the arithmetic mean of the non-null values in the array.
+///
+/// The values are summed with [`sum_primitive`] and thus in `T`, which may overflow for integers.
+/// Returns `None` if the array is empty or only contains null values.
+pub fn mean_primitive<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: NativeType + Simd + Add<Output = T> + std::iter::Sum<T> + AsPrimitive<f64>,
+    T::Simd: Add<Output = T::Simd> + Sum<T>,
+{
+    let count = array.len() - array.null_count();
+    sum_primitive(array).map(|sum| sum.as_() / count as f64)
+}
+
 /// Whether [`sum`] supports `data_type`
 pub fn can_sum(data_type: &DataType) -> bool {
     if let PhysicalType::Primitive(primitive) = data_type.to_physical_type() {
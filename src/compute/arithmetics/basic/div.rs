@@ -1,9 +1,10 @@
 //! Definition of basic div operations with primitive arrays
 use std::ops::Div;
 
-use num_traits::{CheckedDiv, NumCast};
+use num_traits::{CheckedDiv, NumCast, Zero};
 
 use crate::datatypes::PrimitiveType;
+use crate::error::{ArrowError, Result};
 use crate::{
     array::{Array, PrimitiveArray},
     compute::{
@@ -73,6 +74,80 @@ where
     binary_checked(lhs, rhs, lhs.data_type().clone(), op)
 }
 
+/// Divides two primitive arrays with the same type, where a division by zero is null.
+/// Floats follow IEEE 754 instead, where a division by zero is `inf`, `-inf` or `NaN`.
+/// Panics if one pair of values overflows (e.g. `i32::MIN / -1`).
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::divide;
+/// use arrow2::array::{Float64Array, Int32Array};
+///
+/// let a = Int32Array::from(&[Some(10), Some(1), None]);
+/// let b = Int32Array::from(&[Some(5), Some(0), Some(0)]);
+/// let result = divide(&a, &b);
+/// let expected = Int32Array::from(&[Some(2), None, None]);
+/// assert_eq!(result, expected);
+///
+/// let a = Float64Array::from_slice(&[1.0]);
+/// let b = Float64Array::from_slice(&[0.0]);
+/// let result = divide(&a, &b);
+/// assert_eq!(result, Float64Array::from_slice(&[f64::INFINITY]));
+/// ```
+pub fn divide<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Div<Output = T> + Zero,
+{
+    match T::PRIMITIVE {
+        PrimitiveType::Float32 | PrimitiveType::Float64 => div(lhs, rhs),
+        _ => {
+            let op = move |a: T, b: T| if b.is_zero() { None } else { Some(a / b) };
+
+            binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+        }
+    }
+}
+
+/// Divides two primitive arrays with the same type, returning an error on division by zero.
+/// Floats follow IEEE 754 instead, where a division by zero is `inf`, `-inf` or `NaN`.
+/// Panics if one pair of values overflows (e.g. `i32::MIN / -1`).
+///
+/// # Errors
+/// This function errors iff the arrays have a different length or a non-null value of an
+/// integer divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::try_div;
+/// use arrow2::array::Int32Array;
+///
+/// let a = Int32Array::from(&[Some(10), Some(1), Some(6)]);
+/// let b = Int32Array::from(&[Some(5), None, Some(6)]);
+/// let result = try_div(&a, &b).unwrap();
+/// let expected = Int32Array::from(&[Some(2), None, Some(1)]);
+/// assert_eq!(result, expected);
+///
+/// let b = Int32Array::from(&[Some(5), Some(0), Some(6)]);
+/// assert!(try_div(&a, &b).is_err());
+/// ```
+pub fn try_div<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeArithmetics + Div<Output = T> + Zero,
+{
+    check_same_len(lhs, rhs)?;
+    match T::PRIMITIVE {
+        PrimitiveType::Float32 | PrimitiveType::Float64 => {}
+        _ => {
+            if rhs.iter().flatten().any(|x| x.is_zero()) {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Division by zero".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(div(lhs, rhs))
+}
+
 // Implementation of ArrayDiv trait for PrimitiveArrays
 impl<T> ArrayDiv<PrimitiveArray<T>> for PrimitiveArray<T>
 where
@@ -0,0 +1,68 @@
+//! Definition of element-wise min and max operations with primitive arrays
+use crate::{array::PrimitiveArray, compute::arity::binary};
+
+use super::NativeArithmetics;
+
+#[inline]
+fn min_nan<T: PartialOrd>(a: T, b: T) -> T {
+    match a.partial_cmp(&b) {
+        Some(std::cmp::Ordering::Greater) => b,
+        Some(_) => a,
+        None if a.partial_cmp(&a).is_none() => a,
+        None => b,
+    }
+}
+
+#[inline]
+fn max_nan<T: PartialOrd>(a: T, b: T) -> T {
+    match a.partial_cmp(&b) {
+        Some(std::cmp::Ordering::Less) => b,
+        Some(_) => a,
+        None if a.partial_cmp(&a).is_none() => a,
+        None => b,
+    }
+}
+
+/// Element-wise minimum of two primitive arrays with the same type.
+/// If any of the two values is `NaN`, the result is `NaN`.
+/// Panics if the arrays have a different length.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::min;
+/// use arrow2::array::Int32Array;
+///
+/// let a = Int32Array::from(&[Some(1), Some(5), None]);
+/// let b = Int32Array::from(&[Some(2), Some(3), Some(4)]);
+/// let result = min(&a, &b);
+/// let expected = Int32Array::from(&[Some(1), Some(3), None]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn min<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + PartialOrd,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), min_nan)
+}
+
+/// Element-wise maximum of two primitive arrays with the same type.
+/// If any of the two values is `NaN`, the result is `NaN`.
+/// Panics if the arrays have a different length.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::max;
+/// use arrow2::array::Int32Array;
+///
+/// let a = Int32Array::from(&[Some(1), Some(5), None]);
+/// let b = Int32Array::from(&[Some(2), Some(3), Some(4)]);
+/// let result = max(&a, &b);
+/// let expected = Int32Array::from(&[Some(2), Some(5), None]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn max<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + PartialOrd,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), max_nan)
+}
@@ -9,6 +9,8 @@ mod add;
 pub use add::*;
 mod div;
 pub use div::*;
+mod min_max;
+pub use min_max::*;
 mod mul;
 pub use mul::*;
 mod pow;
@@ -20,7 +22,7 @@ pub use sub::*;
 
 use std::ops::Neg;
 
-use num_traits::{CheckedNeg, WrappingNeg};
+use num_traits::{CheckedNeg, Signed, WrappingNeg};
 
 use crate::{array::PrimitiveArray, types::NativeType};
 
@@ -99,3 +101,29 @@ where
 {
     unary(array, |a| a.wrapping_neg(), array.data_type().clone())
 }
+
+/// Absolute value of values from array.
+/// Like [`negate`], this is applied to all values, including those on null slots.
+/// The absolute value of an integer's minimum overflows (e.g. `i8::MIN`), like [`i8::abs`].
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::abs;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(0), Some(7)]);
+/// let result = abs(&a);
+/// let expected = PrimitiveArray::from([None, Some(6), Some(0), Some(7)]);
+/// assert_eq!(result, expected);
+///
+/// let a = PrimitiveArray::from([Some(-1.5f64), Some(f64::NEG_INFINITY)]);
+/// let result = abs(&a);
+/// let expected = PrimitiveArray::from([Some(1.5f64), Some(f64::INFINITY)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed,
+{
+    unary(array, |a| a.abs(), array.data_type().clone())
+}
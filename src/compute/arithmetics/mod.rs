@@ -14,6 +14,7 @@
 #[forbid(unsafe_code)]
 pub mod basic;
 pub mod decimal;
+pub mod scalar;
 pub mod time;
 
 use crate::{
@@ -108,19 +109,19 @@ macro_rules! primitive_scalar {
             .as_any()
             .downcast_ref::<PrimitiveArray<$type>>()
             .unwrap();
-        let rhs = $rhs
-            .as_any()
-            .downcast_ref::<PrimitiveScalar<$type>>()
-            .unwrap();
-
-        let rhs = if let Some(rhs) = rhs.value() {
-            rhs
-        } else {
+        // a null scalar may be a typed `NullScalar` and thus not downcast to `PrimitiveScalar`
+        if !$rhs.is_valid() {
             return Box::new(PrimitiveArray::<$type>::new_null(
                 lhs.data_type().clone(),
                 lhs.len(),
             )) as Box<dyn Array>;
-        };
+        }
+        let rhs = $rhs
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<$type>>()
+            .unwrap()
+            .value()
+            .unwrap();
 
         let result = basic::$op::<$type>(lhs, &rhs);
         Box::new(result) as Box<dyn Array>
@@ -0,0 +1,123 @@
+//! Arithmetic kernels between a [`PrimitiveArray`] and a [`PrimitiveScalar`] of the same type.
+//!
+//! A null scalar yields an all-null array. Addition, subtraction and multiplication
+//! wrap on overflow, like [`basic::wrapping_add_scalar`] and friends; division has the
+//! semantics of [`basic::div_scalar`].
+use std::ops::Div;
+
+use num_traits::{NumCast, WrappingAdd, WrappingMul, WrappingSub};
+
+use crate::{array::PrimitiveArray, scalar::PrimitiveScalar};
+
+use super::basic::{self, NativeArithmetics};
+
+#[inline]
+fn with_scalar<T, F>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveScalar<T>, op: F) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics,
+    F: Fn(&PrimitiveArray<T>, &T) -> PrimitiveArray<T>,
+{
+    match rhs.value() {
+        Some(rhs) => op(lhs, &rhs),
+        None => PrimitiveArray::<T>::new_null(lhs.data_type().clone(), lhs.len()),
+    }
+}
+
+/// Adds a [`PrimitiveScalar`] to a [`PrimitiveArray`] of the same type.
+/// Wraps on overflow.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::scalar::add_primitive_scalar;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let a = PrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// let result = add_primitive_scalar(&a, &PrimitiveScalar::from(Some(1i32)));
+/// let expected = PrimitiveArray::from([None, Some(7), None, Some(7)]);
+/// assert_eq!(result, expected);
+///
+/// let result = add_primitive_scalar(&a, &PrimitiveScalar::from(None::<i32>));
+/// assert_eq!(result.null_count(), 4);
+/// ```
+pub fn add_primitive_scalar<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveScalar<T>,
+) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + WrappingAdd<Output = T>,
+{
+    with_scalar(lhs, rhs, basic::wrapping_add_scalar)
+}
+
+/// Subtracts a [`PrimitiveScalar`] from a [`PrimitiveArray`] of the same type.
+/// Wraps on overflow.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::scalar::sub_primitive_scalar;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let a = PrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// let result = sub_primitive_scalar(&a, &PrimitiveScalar::from(Some(1i32)));
+/// let expected = PrimitiveArray::from([None, Some(5), None, Some(5)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn sub_primitive_scalar<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveScalar<T>,
+) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + WrappingSub<Output = T>,
+{
+    with_scalar(lhs, rhs, basic::wrapping_sub_scalar)
+}
+
+/// Multiplies a [`PrimitiveArray`] by a [`PrimitiveScalar`] of the same type.
+/// Wraps on overflow.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::scalar::mul_primitive_scalar;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let a = PrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// let result = mul_primitive_scalar(&a, &PrimitiveScalar::from(Some(2i32)));
+/// let expected = PrimitiveArray::from([None, Some(12), None, Some(12)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn mul_primitive_scalar<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveScalar<T>,
+) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + WrappingMul<Output = T>,
+{
+    with_scalar(lhs, rhs, basic::wrapping_mul_scalar)
+}
+
+/// Divides a [`PrimitiveArray`] by a [`PrimitiveScalar`] of the same type.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::scalar::div_primitive_scalar;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let a = PrimitiveArray::from([None, Some(6), None, Some(6)]);
+/// let result = div_primitive_scalar(&a, &PrimitiveScalar::from(Some(2i32)));
+/// let expected = PrimitiveArray::from([None, Some(3), None, Some(3)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn div_primitive_scalar<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveScalar<T>,
+) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Div<Output = T> + NumCast,
+{
+    with_scalar(lhs, rhs, basic::div_scalar)
+}
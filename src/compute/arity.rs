@@ -1,5 +1,7 @@
 //! Defines kernels suitable to perform operations to primitive arrays.
 
+use either::Either;
+
 use super::utils::{check_same_len, combine_validities};
 use crate::{
     array::PrimitiveArray,
@@ -30,6 +32,32 @@ where
     PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
 }
 
+/// Applies an unary and infallible function to an owned [`PrimitiveArray`], re-using its
+/// values when they are not shared (see [`Buffer::into_mut`](crate::buffer::Buffer::into_mut))
+/// and allocating a new buffer otherwise. The validity is preserved.
+///
+/// # Implementation
+/// Like [`unary`], this will apply the function for all values, including those on null slots.
+pub fn unary_in_place<T, F>(array: PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T) -> T,
+{
+    let (data_type, values, validity) = array.into_data();
+    let len = values.len();
+    let values = match values.into_mut() {
+        Either::Right(mut values) => {
+            // the buffer may be a slice `(0, len)` of a longer vector
+            values.truncate(len);
+            values.iter_mut().for_each(|v| *v = op(*v));
+            values.into()
+        }
+        Either::Left(values) => values.iter().map(|v| op(*v)).collect::<Vec<_>>().into(),
+    };
+
+    PrimitiveArray::<T>::new(data_type, values, validity)
+}
+
 /// Version of unary that checks for errors in the closure used to create the
 /// buffer
 pub fn try_unary<I, F, O>(
@@ -131,8 +159,10 @@ where
 /// Applies a binary operations to two primitive arrays. This is the fastest
 /// way to perform an operation on two primitive array when the benefits of a
 /// vectorized operation outweighs the cost of branching nulls and non-nulls.
-/// # Errors
-/// This function errors iff the arrays have a different length.
+/// The validity of the result is the intersection of the validities of both arrays.
+/// # Panics
+/// This function panics iff the arrays have a different length. Use [`try_binary`] to
+/// error instead.
 /// # Implementation
 /// This will apply the function for all values, including those on null slots.
 /// This implies that the operation must be infallible for any value of the
@@ -142,16 +172,17 @@ where
 /// resulting array has to be selected by the implementer of the function as
 /// an argument for the function.
 #[inline]
-pub fn binary<T, D, F>(
+pub fn binary<T, D, F, O>(
     lhs: &PrimitiveArray<T>,
     rhs: &PrimitiveArray<D>,
     data_type: DataType,
     op: F,
-) -> PrimitiveArray<T>
+) -> PrimitiveArray<O>
 where
     T: NativeType,
     D: NativeType,
-    F: Fn(T, D) -> T,
+    O: NativeType,
+    F: Fn(T, D) -> O,
 {
     check_same_len(lhs, rhs).unwrap();
 
@@ -165,21 +196,24 @@ where
         .collect::<Vec<_>>()
         .into();
 
-    PrimitiveArray::<T>::new(data_type, values, validity)
+    PrimitiveArray::<O>::new(data_type, values, validity)
 }
 
 /// Version of binary that checks for errors in the closure used to create the
 /// buffer
-pub fn try_binary<T, D, F>(
+/// # Errors
+/// This function errors iff the arrays have a different length or `op` errors.
+pub fn try_binary<T, D, F, O>(
     lhs: &PrimitiveArray<T>,
     rhs: &PrimitiveArray<D>,
     data_type: DataType,
     op: F,
-) -> Result<PrimitiveArray<T>>
+) -> Result<PrimitiveArray<O>>
 where
     T: NativeType,
     D: NativeType,
-    F: Fn(T, D) -> Result<T>,
+    O: NativeType,
+    F: Fn(T, D) -> Result<O>,
 {
     check_same_len(lhs, rhs)?;
 
@@ -193,7 +227,52 @@ where
         .collect::<Result<Vec<_>>>()?
         .into();
 
-    Ok(PrimitiveArray::<T>::new(data_type, values, validity))
+    Ok(PrimitiveArray::<O>::new(data_type, values, validity))
+}
+
+/// Version of [`try_binary`] that sets the result to null on the slots where `op` errors,
+/// instead of returning the error.
+/// Like [`binary`], `op` is also applied to null slots, where its errors are ignored.
+/// # Errors
+/// This function errors iff the arrays have a different length.
+pub fn try_binary_or_null<T, D, F, O>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<D>,
+    data_type: DataType,
+    op: F,
+) -> Result<PrimitiveArray<O>>
+where
+    T: NativeType,
+    D: NativeType,
+    O: NativeType,
+    F: Fn(T, D) -> Result<O>,
+{
+    check_same_len(lhs, rhs)?;
+
+    let mut mut_bitmap = MutableBitmap::with_capacity(lhs.len());
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(l, r)| match op(*l, *r) {
+            Ok(val) => {
+                mut_bitmap.push(true);
+                val
+            }
+            Err(_) => {
+                mut_bitmap.push(false);
+                O::default()
+            }
+        })
+        .collect::<Vec<_>>()
+        .into();
+
+    let bitmap: Bitmap = mut_bitmap.into();
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let validity = combine_validities(validity.as_ref(), Some(&bitmap));
+
+    Ok(PrimitiveArray::<O>::new(data_type, values, validity))
 }
 
 /// Version of binary that returns an array and bitmap. Used when working with
@@ -186,24 +186,16 @@ pub fn or_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray {
     }
 }
 
-/// Check if any of the values in the array is `true`
+/// Returns whether any of the non-null values in the array is `true`.
+///
+/// Null values are ignored; in particular, an empty or all-null array returns `false`.
 pub fn any(array: &BooleanArray) -> bool {
-    if array.is_empty() {
-        false
-    } else if array.validity().is_some() {
-        array.into_iter().any(|v| v == Some(true))
-    } else {
-        let vals = array.values();
-        vals.null_count() != vals.len()
-    }
+    array.count_true() > 0
 }
 
-/// Check if all of the values in the array are `true`
+/// Returns whether all of the non-null values in the array are `true`.
+///
+/// Null values are ignored; in particular, an empty or all-null array returns `true`.
 pub fn all(array: &BooleanArray) -> bool {
-    if array.is_empty() || array.null_count() > 0 {
-        false
-    } else {
-        let vals = array.values();
-        vals.null_count() == 0
-    }
+    array.count_false() == 0
 }
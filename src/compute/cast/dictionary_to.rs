@@ -47,10 +47,7 @@ pub fn wrapping_dictionary_to_dictionary_values<K: DictionaryKey>(
     let values = cast(
         values.as_ref(),
         values_type,
-        CastOptions {
-            wrapped: true,
-            partial: false,
-        },
+        CastOptions::default().with_wrapped(true),
     )?
     .into();
     Ok(DictionaryArray::from_data(keys.clone(), values))
@@ -20,7 +20,10 @@ use crate::{
     error::{ArrowError, Result},
 };
 
-/// options defining how Cast kernels behave
+/// options defining how Cast kernels behave.
+///
+/// Prefer constructing it via [`CastOptions::default`] and its `with_*` methods,
+/// e.g. `CastOptions::default().with_checked(true)`, which is robust to new options.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct CastOptions {
     /// default to false
@@ -30,13 +33,31 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to false
+    /// whether an overflowing numeric cast, or a string that cannot be parsed as a number,
+    /// should return an error instead of being converted to `None`.
+    /// Ignored when `wrapped` (for numeric casts) or `partial` (for strings) is `true`.
+    pub checked: bool,
 }
 
 impl CastOptions {
-    fn with_wrapped(&self, v: bool) -> Self {
-        let mut option = *self;
-        option.wrapped = v;
-        option
+    /// Sets whether overflowing numeric casts are wrapped (see [`CastOptions::wrapped`]).
+    pub fn with_wrapped(mut self, v: bool) -> Self {
+        self.wrapped = v;
+        self
+    }
+
+    /// Sets whether strings are cast to numbers at best-effort (see [`CastOptions::partial`]).
+    pub fn with_partial(mut self, v: bool) -> Self {
+        self.partial = v;
+        self
+    }
+
+    /// Sets whether overflowing numeric casts and unparsable strings error
+    /// (see [`CastOptions::checked`]).
+    pub fn with_checked(mut self, v: bool) -> Self {
+        self.checked = v;
+        self
     }
 }
 
@@ -287,6 +308,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Int64, Timestamp(_, _)) => true,
         (Timestamp(_, _), Timestamp(_, _)) => true,
         (Timestamp(_, _), Date32) => true,
+        (Date32, Timestamp(_, _)) => true,
         (Timestamp(_, _), Date64) => true,
         (Int64, Duration(_)) => true,
         (Duration(_), Int64) => true,
@@ -316,7 +338,8 @@ fn cast_list<O: Offset>(
     ))
 }
 
-fn cast_list_to_large_list(array: &ListArray<i32>, to_type: &DataType) -> ListArray<i64> {
+/// Casts a [`ListArray<i32>`] to a [`ListArray<i64>`] of type `to_type`, sharing its values.
+pub fn cast_list_to_large_list(array: &ListArray<i32>, to_type: &DataType) -> ListArray<i64> {
     let offets = array
         .offsets()
         .iter()
@@ -332,7 +355,13 @@ fn cast_list_to_large_list(array: &ListArray<i32>, to_type: &DataType) -> ListAr
     )
 }
 
-fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i32> {
+/// Casts a [`ListArray<i64>`] to a [`ListArray<i32>`] of type `to_type`, sharing its values.
+/// # Errors
+/// This function errors iff an offset of `array` does not fit in an `i32`.
+pub fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> Result<ListArray<i32>> {
+    let _ =
+        i32::try_from(*array.offsets().last().unwrap()).map_err(ArrowError::from_external_error)?;
+
     let offsets = array
         .offsets()
         .iter()
@@ -340,12 +369,12 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
         .collect::<Vec<_>>()
         .into();
 
-    ListArray::<i32>::new(
+    Ok(ListArray::<i32>::new(
         to_type.clone(),
         offsets,
         array.values().clone(),
         array.validity().cloned(),
-    )
+    ))
 }
 
 /// Cast `array` to the provided data type and return a new [`Array`] with
@@ -408,11 +437,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             to_type,
         ))
         .map(|x| Box::new(x) as Box<dyn Array>),
-        (LargeList(lhs), List(rhs)) if lhs == rhs => Ok(cast_large_to_list(
-            array.as_any().downcast_ref().unwrap(),
-            to_type,
-        ))
-        .map(|x| Box::new(x) as Box<dyn Array>),
+        (LargeList(lhs), List(rhs)) if lhs == rhs => {
+            cast_large_to_list(array.as_any().downcast_ref().unwrap(), to_type)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
 
         (_, List(to)) => {
             // cast primitive to list's primitive
@@ -839,6 +867,9 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         }
         (Timestamp(from_unit, _), Date32) => primitive_dyn!(array, timestamp_to_date32, *from_unit),
         (Timestamp(from_unit, _), Date64) => primitive_dyn!(array, timestamp_to_date64, *from_unit),
+        (Date32, Timestamp(to_unit, tz)) => {
+            primitive_dyn!(array, date32_to_timestamp, *to_unit, tz)
+        }
 
         (Int64, Duration(_)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Duration(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
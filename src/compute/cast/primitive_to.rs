@@ -3,12 +3,12 @@ use std::hash::Hash;
 use num_traits::{AsPrimitive, Float, ToPrimitive};
 
 use crate::datatypes::IntervalUnit;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::types::{days_ms, months_days_ns};
 use crate::{
     array::*,
     bitmap::Bitmap,
-    compute::arity::unary,
+    compute::arity::{unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
     types::NativeType,
@@ -137,6 +137,8 @@ where
     let from = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
     if options.wrapped {
         Ok(Box::new(primitive_as_primitive::<I, O>(from, to_type)))
+    } else if options.checked {
+        Ok(Box::new(try_primitive_to_primitive::<I, O>(from, to_type)?))
     } else {
         Ok(Box::new(primitive_to_primitive::<I, O>(from, to_type)))
     }
@@ -157,6 +159,36 @@ where
     PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
 }
 
+/// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion,
+/// erroring on overflow. Nulls remain nulls.
+/// # Errors
+/// This function errors iff a non-null value cannot be represented in `O`.
+pub fn try_primitive_to_primitive<I, O>(
+    from: &PrimitiveArray<I>,
+    to_type: &DataType,
+) -> Result<PrimitiveArray<O>>
+where
+    I: NativeType + num_traits::NumCast,
+    O: NativeType + num_traits::NumCast,
+{
+    let array = from
+        .iter()
+        .map(|x| {
+            x.map(|x| {
+                num_traits::cast::cast::<I, O>(*x).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Casting {:?} to {:?} overflows",
+                        x, to_type
+                    ))
+                })
+            })
+            .transpose()
+        })
+        .collect::<Result<MutablePrimitiveArray<O>>>()?;
+
+    Ok(PrimitiveArray::<O>::from(array).to(to_type.clone()))
+}
+
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
 pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
     from: &PrimitiveArray<T>,
@@ -372,6 +404,18 @@ pub fn timestamp_to_date32(from: &PrimitiveArray<i64>, from_unit: TimeUnit) -> P
     unary(from, |x| (x / from_size) as i32, DataType::Date32)
 }
 
+/// Conversion of dates to timestamps at midnight.
+/// Dates whose timestamp overflows `i64` (e.g. beyond the year 2262 in nanoseconds) are set to null.
+pub fn date32_to_timestamp(
+    from: &PrimitiveArray<i32>,
+    to_unit: TimeUnit,
+    tz: &Option<String>,
+) -> PrimitiveArray<i64> {
+    let to_size = time_unit_multiple(to_unit) * SECONDS_IN_DAY;
+    let to_type = DataType::Timestamp(to_unit, tz.clone());
+    unary_checked(from, |x| (x as i64).checked_mul(to_size), to_type)
+}
+
 /// Conversion of time
 pub fn time32_to_time64(
     from: &PrimitiveArray<i32>,
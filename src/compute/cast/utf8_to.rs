@@ -29,6 +29,35 @@ where
     PrimitiveArray::<T>::from_trusted_len_iter(iter).to(to.clone())
 }
 
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray`], erroring on any uncastable value.
+/// Nulls remain nulls.
+/// # Errors
+/// This function errors iff a non-null value cannot be parsed as `T`.
+pub fn try_utf8_to_primitive<O: Offset, T>(
+    from: &Utf8Array<O>,
+    to: &DataType,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + lexical_core::FromLexical,
+{
+    let array = from
+        .iter()
+        .map(|x| {
+            x.map(|x| {
+                lexical_core::parse::<T>(x.as_bytes()).map_err(|_| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Could not parse \"{}\" as {:?}",
+                        x, to
+                    ))
+                })
+            })
+            .transpose()
+        })
+        .collect::<Result<MutablePrimitiveArray<T>>>()?;
+
+    Ok(PrimitiveArray::<T>::from(array).to(to.clone()))
+}
+
 /// Casts a [`Utf8Array`] to a [`PrimitiveArray`] at best-effort using `lexical_core::parse_partial`, making any uncastable value as zero.
 pub fn partial_utf8_to_primitive<O: Offset, T>(
     from: &Utf8Array<O>,
@@ -55,6 +84,8 @@ where
     let from = from.as_any().downcast_ref().unwrap();
     if options.partial {
         Ok(Box::new(partial_utf8_to_primitive::<O, T>(from, to)))
+    } else if options.checked {
+        Ok(Box::new(try_utf8_to_primitive::<O, T>(from, to)?))
     } else {
         Ok(Box::new(utf8_to_primitive::<O, T>(from, to)))
     }
@@ -0,0 +1,63 @@
+//! Defines kernels to delta-encode and decode [`PrimitiveArray<i64>`], whereby each value
+//! is replaced by its difference to the previous value.
+//!
+//! This encoding is useful for sorted (or nearly sorted) arrays, whose deltas are small
+//! and thus compress well.
+use crate::array::PrimitiveArray;
+
+/// Returns the delta encoding of `array`: the first value is kept unchanged and every other
+/// value is replaced by its difference to the previous value.
+/// The validity is kept unchanged; values in null slots are encoded like any other value.
+/// Differences wrap around on overflow so that [`decode`] always recovers the original values.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::delta::encode;
+///
+/// let array = PrimitiveArray::<i64>::from_slice([1, 3, 6, 4]);
+/// let result = encode(&array);
+/// assert_eq!(result, PrimitiveArray::<i64>::from_slice([1, 2, 3, -2]));
+/// ```
+pub fn encode(array: &PrimitiveArray<i64>) -> PrimitiveArray<i64> {
+    let values = array.values();
+    let deltas = values
+        .iter()
+        .take(1)
+        .copied()
+        .chain(values.windows(2).map(|w| w[1].wrapping_sub(w[0])))
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::<i64>::new(
+        array.data_type().clone(),
+        deltas.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// Returns the values of a delta-encoded `array` (the inverse of [`encode`]), computed as
+/// the prefix sum of the deltas.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::delta::decode;
+///
+/// let array = PrimitiveArray::<i64>::from_slice([1, 2, 3, -2]);
+/// let result = decode(&array);
+/// assert_eq!(result, PrimitiveArray::<i64>::from_slice([1, 3, 6, 4]));
+/// ```
+pub fn decode(array: &PrimitiveArray<i64>) -> PrimitiveArray<i64> {
+    let values = array
+        .values()
+        .iter()
+        .scan(0i64, |acc, delta| {
+            *acc = acc.wrapping_add(*delta);
+            Some(*acc)
+        })
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::<i64>::new(
+        array.data_type().clone(),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
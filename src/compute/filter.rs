@@ -1,10 +1,12 @@
 //! Contains operators to filter arrays such as [`filter`].
+use std::sync::Arc;
+
 use crate::array::growable::{make_growable, Growable};
 use crate::bitmap::utils::{BitChunk, BitChunkIterExact, BitChunksExact};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
 use crate::chunk::Chunk;
 use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::types::simd::{NativeSimd, Simd};
 use crate::types::BitChunkOnes;
 use crate::{array::*, types::NativeType};
@@ -280,6 +282,18 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<$T>(array, filter)))
         }),
+        Struct => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(filter_struct(array, filter)?))
+        }
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            Ok(Box::new(filter_list(array, filter)?))
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            Ok(Box::new(filter_list(array, filter)?))
+        }
         _ => {
             let iter = SlicesIterator::new(filter.values());
             let mut mutable = make_growable(&[array], false, iter.slots());
@@ -289,6 +303,104 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
     }
 }
 
+/// Filters a [`StructArray`] by filtering each of its fields individually (via [`filter`])
+/// and its validity, returning the entries where `filter` is true.
+/// # Errors
+/// Errors iff the length of `array` and `filter` differ.
+pub fn filter_struct(array: &StructArray, filter: &BooleanArray) -> Result<StructArray> {
+    if array.len() != filter.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Filter's length must be equal to the array's length".to_string(),
+        ));
+    }
+
+    let values = array
+        .values()
+        .iter()
+        .map(|values| self::filter(values.as_ref(), filter).map(Arc::from))
+        .collect::<Result<Vec<_>>>()?;
+
+    let validity = array
+        .validity()
+        .map(|validity| {
+            let validity = BooleanArray::new(DataType::Boolean, validity.clone(), None);
+            self::filter(&validity, filter).map(|validity| {
+                let validity = validity.as_any().downcast_ref::<BooleanArray>().unwrap();
+                validity.values().clone()
+            })
+        })
+        .transpose()?;
+
+    Ok(StructArray::new(
+        array.data_type().clone(),
+        values,
+        validity,
+    ))
+}
+
+/// Filters a [`ListArray`], returning the entries where `filter` is true.
+///
+/// The child values of the kept entries are copied into a new, compacted child array,
+/// so the result does not reference values of entries that were filtered out.
+/// Nulls of `filter` are interpreted as `false`.
+/// # Errors
+/// Errors iff the length of `array` and `filter` differ.
+pub fn filter_list<O: Offset>(array: &ListArray<O>, filter: &BooleanArray) -> Result<ListArray<O>> {
+    if array.len() != filter.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Filter's length must be equal to the array's length".to_string(),
+        ));
+    }
+
+    let mask = match filter.validity() {
+        Some(validity) => filter.values() & validity,
+        None => filter.values().clone(),
+    };
+
+    let iter = SlicesIterator::new(&mask);
+    let filter_count = iter.slots();
+    let chunks = iter.collect::<Vec<_>>();
+
+    // the offsets of a sliced array are sliced too, so they index directly into `values`
+    let offsets = array.offsets().as_slice();
+    let child_length: usize = chunks
+        .iter()
+        .map(|&(start, len)| offsets[start + len].to_usize() - offsets[start].to_usize())
+        .sum();
+
+    let mut new_offsets = Vec::<O>::with_capacity(filter_count + 1);
+    new_offsets.push(O::default());
+    let mut values = make_growable(&[array.values().as_ref()], false, child_length);
+    let mut length = 0;
+    for &(start, len) in chunks.iter() {
+        let child_start = offsets[start].to_usize();
+        new_offsets.extend(
+            offsets[start + 1..start + len + 1]
+                .iter()
+                .map(|offset| O::from_usize(length + offset.to_usize() - child_start).unwrap()),
+        );
+        let child_len = offsets[start + len].to_usize() - child_start;
+        values.extend(0, child_start, child_len);
+        length += child_len;
+    }
+
+    let validity = array.validity().map(|validity| {
+        let (slice, offset, _) = validity.as_slice();
+        let mut new_validity = MutableBitmap::with_capacity(filter_count);
+        chunks
+            .iter()
+            .for_each(|&(start, len)| new_validity.extend_from_slice(slice, offset + start, len));
+        new_validity.into()
+    });
+
+    Ok(ListArray::<O>::new(
+        array.data_type().clone(),
+        new_offsets.into(),
+        values.as_arc(),
+        validity,
+    ))
+}
+
 /// Returns a new [Chunk] with arrays containing only values matching the filter.
 /// This is a convenience function: filter multiple columns is embarassingly parallel.
 pub fn filter_chunk<A: AsRef<dyn Array>>(
@@ -310,3 +422,41 @@ pub fn filter_chunk<A: AsRef<dyn Array>>(
     };
     Chunk::try_new(filtered_arrays)
 }
+
+/// Returns the indices of the slots of `mask` that are `true`, e.g. to take the
+/// same selection from multiple arrays.
+///
+/// Like in [`filter`], the nulls of `mask` are interpreted as `false` and are thus not selected.
+/// The mask is scanned 64 slots at a time.
+/// # Panics
+/// This function panics iff the length of `mask` exceeds `i32::MAX`.
+/// # Example
+/// ```rust
+/// # use arrow2::array::{BooleanArray, Int32Array};
+/// # use arrow2::compute::filter::mask_to_indices;
+/// let mask = BooleanArray::from(&[Some(true), None, Some(false), Some(true)]);
+/// assert_eq!(mask_to_indices(&mask), Int32Array::from_slice(&[0, 3]));
+/// ```
+pub fn mask_to_indices(mask: &BooleanArray) -> PrimitiveArray<i32> {
+    assert!(mask.len() <= i32::MAX as usize);
+    let values = match mask.validity() {
+        Some(validity) => mask.values() & validity,
+        None => mask.values().clone(),
+    };
+
+    let mut indices = Vec::<i32>::with_capacity(values.len() - values.null_count());
+    let mut chunks = values.chunks::<u64>();
+    let mut offset = 0;
+    chunks.by_ref().for_each(|chunk| {
+        indices.extend(BitChunkOnes::new(chunk).map(|pos| (offset + pos) as i32));
+        offset += 64;
+    });
+    indices.extend(
+        chunks
+            .remainder_iter()
+            .enumerate()
+            .filter_map(|(pos, is_set)| is_set.then(|| (offset + pos) as i32)),
+    );
+
+    PrimitiveArray::new(DataType::Int32, indices.into(), None)
+}
@@ -0,0 +1,51 @@
+//! Contains the operator [`extract`], to extract values from JSON-encoded utf8 arrays.
+use serde_json::Value;
+
+use crate::array::{Offset, Utf8Array};
+use crate::error::{ArrowError, Result};
+
+fn extract_value<'a>(value: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |value, key| match value {
+        Value::Object(map) => map.get(*key),
+        Value::Array(values) => key.parse::<usize>().ok().and_then(|i| values.get(i)),
+        _ => None,
+    })
+}
+
+/// Parses each value of `array` as JSON and returns the value at `path`, a dotted path
+/// of object keys or array indices (e.g. `a.b` or `a.0`).
+///
+/// JSON strings are returned unquoted, while any other JSON value is returned serialized.
+/// The result is null when the value is null, is not valid JSON, does not contain `path`
+/// or when the value at `path` is the JSON `null`.
+/// # Error
+/// Errors iff `path` is empty.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::json::extract;
+///
+/// let array = Utf8Array::<i32>::from(&[Some(r#"{"a": {"b": "x"}}"#), Some(r#"{"a": 1}"#), None]);
+/// let result = extract(&array, "a.b").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("x"), None, None]));
+/// let result = extract(&array, "a").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some(r#"{"b":"x"}"#), Some("1"), None]));
+/// ```
+pub fn extract<O: Offset>(array: &Utf8Array<O>, path: &str) -> Result<Utf8Array<O>> {
+    if path.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The path to extract from JSON must not be empty".to_string(),
+        ));
+    }
+    let path = path.split('.').collect::<Vec<_>>();
+
+    let iter = array.iter().map(|x| {
+        let value = serde_json::from_str::<Value>(x?).ok()?;
+        match extract_value(&value, &path)? {
+            Value::Null => None,
+            Value::String(string) => Some(string.clone()),
+            other => Some(other.to_string()),
+        }
+    });
+    Ok(Utf8Array::<O>::from_trusted_len_iter(iter))
+}
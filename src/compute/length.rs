@@ -62,6 +62,58 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     }
 }
 
+/// Returns the number of bytes of each string of `array`, computed from its offsets.
+/// Null values have a null length.
+/// # Panics
+/// This function panics iff a string is longer than `i32::MAX` bytes.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::length::string_byte_length;
+///
+/// let array = Utf8Array::<i64>::from(&[Some("hello"), Some("💖"), None]);
+/// assert_eq!(string_byte_length(&array), Int32Array::from(&[Some(5), Some(4), None]));
+/// ```
+pub fn string_byte_length<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<i32> {
+    let values = array
+        .offsets()
+        .windows(2)
+        .map(|offset| i32::try_from((offset[1] - offset[0]).to_usize()).unwrap())
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::<i32>::new(DataType::Int32, values.into(), array.validity().cloned())
+}
+
+/// Returns the number of characters (unicode scalar values) of each string of `array`.
+/// Null values have a null length.
+/// # Implementation
+/// Characters are counted by scanning the bytes of each string for those that do not continue
+/// a utf8 sequence; no `char` is decoded.
+/// # Panics
+/// This function panics iff a string is longer than `i32::MAX` bytes.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::length::string_length;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("hello"), Some("💖"), None]);
+/// assert_eq!(string_length(&array), Int32Array::from(&[Some(5), Some(1), None]));
+/// ```
+pub fn string_length<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<i32> {
+    let bytes = array.values().as_slice();
+    let values = array
+        .offsets()
+        .windows(2)
+        .map(|offset| {
+            let string = &bytes[offset[0].to_usize()..offset[1].to_usize()];
+            let length = string.iter().filter(|byte| (**byte & 0xC0) != 0x80).count();
+            i32::try_from(length).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::<i32>::new(DataType::Int32, values.into(), array.validity().cloned())
+}
+
 /// Checks if an array of type `datatype` can perform length operation
 ///
 /// # Examples
@@ -64,10 +64,11 @@ use itertools::Itertools;
 use crate::array::{
     growable::make_growable,
     ord::{build_compare, DynComparator},
-    Array,
+    Array, MutablePrimitiveArray, PrimitiveArray,
 };
 pub use crate::compute::sort::SortOptions;
 use crate::error::Result;
+use crate::types::NativeType;
 
 /// A slice denoting `(array_index, start, len)` representing a slice from one of N arrays.
 /// This is used to keep track of contiguous blocks of slots.
@@ -155,6 +156,67 @@ pub fn merge_sort(
     Ok(take_arrays(arrays, slices, limit))
 }
 
+/// Combines two [`PrimitiveArray`]s sorted according to `cmp` and `options` into a single sorted
+/// array of length `lhs.len() + rhs.len()`, whose [`crate::datatypes::DataType`] is the one of `lhs`.
+/// Equal items are taken from `lhs` first.
+///
+/// Unlike [`merge_sort`], this takes items one by one, which is faster when both arrays are
+/// interleaved. If the arrays are not sorted (which this function does not check), the result is wrong.
+/// # Example
+/// ```rust
+/// use arrow2::array::{ord::total_cmp, Int32Array};
+/// use arrow2::compute::merge_sort::{merge_sorted_by, SortOptions};
+///
+/// let a = Int32Array::from(&[None, Some(2), Some(4)]);
+/// let b = Int32Array::from_slice(&[1, 3]);
+/// let sorted = merge_sorted_by(&a, &b, total_cmp, &SortOptions::default());
+/// let expected = Int32Array::from(&[None, Some(1), Some(2), Some(3), Some(4)]);
+/// assert_eq!(sorted, expected);
+/// ```
+pub fn merge_sorted_by<T, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+    cmp: F,
+    options: &SortOptions,
+) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let null_ordering = if options.nulls_first {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    let compare = |lhs: &Option<&T>, rhs: &Option<&T>| match (lhs, rhs) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => null_ordering,
+        (Some(_), None) => null_ordering.reverse(),
+        (Some(lhs), Some(rhs)) if options.descending => cmp(lhs, rhs).reverse(),
+        (Some(lhs), Some(rhs)) => cmp(lhs, rhs),
+    };
+
+    let mut result = MutablePrimitiveArray::<T>::with_capacity_from(
+        lhs.len() + rhs.len(),
+        lhs.data_type().clone(),
+    );
+
+    let mut lhs = lhs.iter().peekable();
+    let mut rhs = rhs.iter().peekable();
+    loop {
+        let item = match (lhs.peek(), rhs.peek()) {
+            (Some(l), Some(r)) if compare(l, r) != Ordering::Greater => lhs.next(),
+            (Some(_), Some(_)) => rhs.next(),
+            (Some(_), None) => lhs.next(),
+            (None, Some(_)) => rhs.next(),
+            (None, None) => break,
+        };
+        result.push(item.unwrap().copied());
+    }
+
+    result.into()
+}
+
 /// Returns a vector of slices from different sorted arrays that can be used to create sorted arrays.
 /// `pairs` is an array representing multiple sorted array sets. The expected format is
 ///
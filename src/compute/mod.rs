@@ -39,7 +39,10 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
-#[cfg(feature = "compute_filter")]
+#[cfg(feature = "compute_delta")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_delta")))]
+pub mod delta;
+#[cfg(any(feature = "compute_filter", feature = "io_parquet"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
 #[cfg(feature = "compute_hash")]
@@ -48,6 +51,9 @@ pub mod hash;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
+#[cfg(feature = "compute_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_json")))]
+pub mod json;
 #[cfg(feature = "compute_length")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_length")))]
 pub mod length;
@@ -69,6 +75,9 @@ pub mod partition;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_row")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_row")))]
+pub mod row;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
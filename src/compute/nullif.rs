@@ -120,9 +120,10 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 
 /// Returns an [`Array`] with the same type as `lhs` and whose validity
 /// is null iff either `lhs == rhs` or `lhs` is null.
+///
+/// Like in SQL's `NULLIF(lhs, NULL)`, a null `rhs` is never equal to `lhs`, and `lhs` is returned.
 /// # Panics
 /// iff
-/// * Scalar is null
 /// * lhs and rhs do not have the same type
 /// * The physical type is not supported for this operation (use [`can_nullif`] to check)
 /// # Example
@@ -143,11 +144,15 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// ```
 pub fn nullif_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
     assert_eq!(lhs.data_type(), rhs.data_type());
+    // a null scalar may be a typed `NullScalar` and thus not downcast to `PrimitiveScalar`
+    if !rhs.is_valid() {
+        return lhs.to_boxed();
+    }
     use crate::datatypes::PhysicalType::*;
     match lhs.data_type().to_physical_type() {
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let scalar = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
-            let scalar = scalar.value().expect("Scalar to be non-null");
+            let scalar = scalar.value().unwrap();
 
             Box::new(primitive_nullif_scalar::<$T>(
                 lhs.as_any().downcast_ref().unwrap(),
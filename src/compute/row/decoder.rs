@@ -0,0 +1,176 @@
+use crate::array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+
+use super::super::sort::SortOptions;
+use super::descending_mask;
+use super::encoder::FixedLengthEncoding;
+
+/// Splits the first `length` bytes from `row`, advancing it.
+#[inline]
+fn split<'a>(row: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    if row.len() < length {
+        return Err(ArrowError::oos(
+            "The row encoding is shorter than declared by its fields",
+        ));
+    }
+    let (head, tail) = row.split_at(length);
+    *row = tail;
+    Ok(head)
+}
+
+/// Decodes the validity byte of a value, advancing `row`.
+#[inline]
+fn decode_validity(row: &mut &[u8]) -> Result<bool> {
+    Ok(split(row, 1)?[0] == 1)
+}
+
+fn decode_primitive<T: FixedLengthEncoding>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: &SortOptions,
+) -> Result<PrimitiveArray<T>> {
+    let mask = descending_mask(options);
+    let size = std::mem::size_of::<T>();
+
+    let mut values = Vec::<T>::with_capacity(rows.len());
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    // large enough for the widest native type (i128)
+    let mut buffer = [0u8; 16];
+    for row in rows.iter_mut() {
+        let is_valid = decode_validity(row)?;
+        let bytes = split(row, size)?;
+        validity.push(is_valid);
+        if is_valid {
+            buffer
+                .iter_mut()
+                .zip(bytes.iter())
+                .for_each(|(dst, src)| *dst = src ^ mask);
+            let bytes: T::Bytes = match buffer[..size].try_into() {
+                Ok(bytes) => bytes,
+                Err(_) => unreachable!(),
+            };
+            values.push(T::decode(bytes));
+        } else {
+            values.push(T::default());
+        }
+    }
+
+    PrimitiveArray::<T>::try_new(data_type, values.into(), validity.into())
+}
+
+fn decode_boolean(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: &SortOptions,
+) -> Result<BooleanArray> {
+    let mask = descending_mask(options);
+
+    let mut values = MutableBitmap::with_capacity(rows.len());
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    for row in rows.iter_mut() {
+        let is_valid = decode_validity(row)?;
+        let value = split(row, 1)?[0] ^ mask;
+        validity.push(is_valid);
+        values.push(is_valid && value == 1);
+    }
+
+    BooleanArray::try_new(data_type, values.into(), validity.into())
+}
+
+/// Decodes a self-delimited variable-length value from `row` into `values`, advancing `row`.
+fn decode_variable(row: &mut &[u8], values: &mut Vec<u8>, mask: u8) -> Result<()> {
+    loop {
+        let byte = split(row, 1)?[0] ^ mask;
+        if byte != 0 {
+            values.push(byte);
+            continue;
+        }
+        match split(row, 1)?[0] ^ mask {
+            0 => return Ok(()),
+            0xFF => values.push(0),
+            _ => return Err(ArrowError::oos("Invalid escape in the row encoding")),
+        }
+    }
+}
+
+/// Returns the offsets, values and validity of a variable-length column.
+fn decode_variable_column<O: Offset>(
+    rows: &mut [&[u8]],
+    options: &SortOptions,
+) -> Result<(Vec<O>, Vec<u8>, MutableBitmap)> {
+    let mask = descending_mask(options);
+
+    let mut offsets = Vec::<O>::with_capacity(rows.len() + 1);
+    let mut values = Vec::<u8>::new();
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    offsets.push(O::default());
+    for row in rows.iter_mut() {
+        let is_valid = decode_validity(row)?;
+        validity.push(is_valid);
+        if is_valid {
+            decode_variable(row, &mut values, mask)?;
+        }
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+    }
+    Ok((offsets, values, validity))
+}
+
+fn decode_binary<O: Offset>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: &SortOptions,
+) -> Result<BinaryArray<O>> {
+    let (offsets, values, validity) = decode_variable_column::<O>(rows, options)?;
+    BinaryArray::<O>::try_new(data_type, offsets.into(), values.into(), validity.into())
+}
+
+fn decode_utf8<O: Offset>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    options: &SortOptions,
+) -> Result<Utf8Array<O>> {
+    let (offsets, values, validity) = decode_variable_column::<O>(rows, options)?;
+    Utf8Array::<O>::try_new(data_type, offsets.into(), values.into(), validity.into())
+}
+
+macro_rules! primitive {
+    ($rows:expr, $type:ty, $data_type:expr, $options:expr) => {
+        Box::new(decode_primitive::<$type>($rows, $data_type, $options)?)
+    };
+}
+
+/// Decodes a column of `data_type` from `rows`, advancing each row past it.
+pub(super) fn decode_column(
+    rows: &mut [&[u8]],
+    data_type: &DataType,
+    options: &SortOptions,
+) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    let data_type = data_type.clone();
+    Ok(match data_type.to_physical_type() {
+        Boolean => Box::new(decode_boolean(rows, data_type, options)?),
+        Primitive(PrimitiveType::Int8) => primitive!(rows, i8, data_type, options),
+        Primitive(PrimitiveType::Int16) => primitive!(rows, i16, data_type, options),
+        Primitive(PrimitiveType::Int32) => primitive!(rows, i32, data_type, options),
+        Primitive(PrimitiveType::Int64) => primitive!(rows, i64, data_type, options),
+        Primitive(PrimitiveType::Int128) => primitive!(rows, i128, data_type, options),
+        Primitive(PrimitiveType::UInt8) => primitive!(rows, u8, data_type, options),
+        Primitive(PrimitiveType::UInt16) => primitive!(rows, u16, data_type, options),
+        Primitive(PrimitiveType::UInt32) => primitive!(rows, u32, data_type, options),
+        Primitive(PrimitiveType::UInt64) => primitive!(rows, u64, data_type, options),
+        Primitive(PrimitiveType::Float32) => primitive!(rows, f32, data_type, options),
+        Primitive(PrimitiveType::Float64) => primitive!(rows, f64, data_type, options),
+        Utf8 => Box::new(decode_utf8::<i32>(rows, data_type, options)?),
+        LargeUtf8 => Box::new(decode_utf8::<i64>(rows, data_type, options)?),
+        Binary => Box::new(decode_binary::<i32>(rows, data_type, options)?),
+        LargeBinary => Box::new(decode_binary::<i64>(rows, data_type, options)?),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Row decoding of {:?}",
+                data_type
+            )))
+        }
+    })
+}
@@ -0,0 +1,206 @@
+use crate::array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::datatypes::{PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::super::sort::SortOptions;
+use super::{descending_mask, validity_markers};
+
+/// A function that appends the encoding of the value at `row` to a buffer.
+pub(super) type Encoder<'a> = Box<dyn Fn(&mut Vec<u8>, usize) + 'a>;
+
+/// A [`NativeType`] that can be encoded into fixed-length bytes whose byte order
+/// equals the order of the values.
+pub(super) trait FixedLengthEncoding: NativeType {
+    /// Returns the order-preserving big-endian representation of `self`.
+    fn encode(self) -> Self::Bytes;
+
+    /// The inverse of [`FixedLengthEncoding::encode`].
+    fn decode(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! unsigned_encoding {
+    ($type:ty) => {
+        impl FixedLengthEncoding for $type {
+            #[inline]
+            fn encode(self) -> Self::Bytes {
+                self.to_be_bytes()
+            }
+
+            #[inline]
+            fn decode(bytes: Self::Bytes) -> Self {
+                Self::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+macro_rules! signed_encoding {
+    ($type:ty) => {
+        impl FixedLengthEncoding for $type {
+            #[inline]
+            fn encode(self) -> Self::Bytes {
+                let mut bytes = self.to_be_bytes();
+                bytes[0] ^= 0x80;
+                bytes
+            }
+
+            #[inline]
+            fn decode(mut bytes: Self::Bytes) -> Self {
+                bytes[0] ^= 0x80;
+                Self::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+macro_rules! float_encoding {
+    ($type:ty) => {
+        impl FixedLengthEncoding for $type {
+            #[inline]
+            fn encode(self) -> Self::Bytes {
+                let mut bytes = self.to_be_bytes();
+                if bytes[0] & 0x80 != 0 {
+                    // negative numbers are ordered in reverse of their bits
+                    bytes.iter_mut().for_each(|byte| *byte = !*byte);
+                } else {
+                    bytes[0] ^= 0x80;
+                }
+                bytes
+            }
+
+            #[inline]
+            fn decode(mut bytes: Self::Bytes) -> Self {
+                if bytes[0] & 0x80 != 0 {
+                    bytes[0] ^= 0x80;
+                } else {
+                    bytes.iter_mut().for_each(|byte| *byte = !*byte);
+                }
+                Self::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+unsigned_encoding!(u8);
+unsigned_encoding!(u16);
+unsigned_encoding!(u32);
+unsigned_encoding!(u64);
+signed_encoding!(i8);
+signed_encoding!(i16);
+signed_encoding!(i32);
+signed_encoding!(i64);
+signed_encoding!(i128);
+float_encoding!(f32);
+float_encoding!(f64);
+
+fn encode_primitive<'a, T: FixedLengthEncoding>(
+    array: &'a PrimitiveArray<T>,
+    options: &SortOptions,
+) -> Encoder<'a> {
+    let (null, valid) = validity_markers(options);
+    let mask = descending_mask(options);
+    Box::new(move |buffer, row| {
+        if array.is_null(row) {
+            buffer.push(null);
+            buffer.resize(buffer.len() + std::mem::size_of::<T>(), 0);
+        } else {
+            buffer.push(valid);
+            let bytes = array.value(row).encode();
+            buffer.extend(bytes.as_ref().iter().map(|byte| byte ^ mask));
+        }
+    })
+}
+
+fn encode_boolean<'a>(array: &'a BooleanArray, options: &SortOptions) -> Encoder<'a> {
+    let (null, valid) = validity_markers(options);
+    let mask = descending_mask(options);
+    Box::new(move |buffer, row| {
+        if array.is_null(row) {
+            buffer.push(null);
+            buffer.push(0);
+        } else {
+            buffer.push(valid);
+            buffer.push(array.value(row) as u8 ^ mask);
+        }
+    })
+}
+
+/// Appends the order-preserving, self-delimiting encoding of `value` to `buffer`.
+#[inline]
+fn encode_variable(buffer: &mut Vec<u8>, value: &[u8], mask: u8) {
+    for byte in value {
+        if *byte == 0 {
+            buffer.push(mask);
+            buffer.push(0xFF ^ mask);
+        } else {
+            buffer.push(byte ^ mask);
+        }
+    }
+    buffer.push(mask);
+    buffer.push(mask);
+}
+
+fn encode_binary<'a, O: Offset>(array: &'a BinaryArray<O>, options: &SortOptions) -> Encoder<'a> {
+    let (null, valid) = validity_markers(options);
+    let mask = descending_mask(options);
+    Box::new(move |buffer, row| {
+        if array.is_null(row) {
+            buffer.push(null);
+        } else {
+            buffer.push(valid);
+            encode_variable(buffer, array.value(row), mask);
+        }
+    })
+}
+
+fn encode_utf8<'a, O: Offset>(array: &'a Utf8Array<O>, options: &SortOptions) -> Encoder<'a> {
+    let (null, valid) = validity_markers(options);
+    let mask = descending_mask(options);
+    Box::new(move |buffer, row| {
+        if array.is_null(row) {
+            buffer.push(null);
+        } else {
+            buffer.push(valid);
+            encode_variable(buffer, array.value(row).as_bytes(), mask);
+        }
+    })
+}
+
+macro_rules! primitive {
+    ($array:expr, $type:ty, $options:expr) => {
+        encode_primitive::<$type>($array.as_any().downcast_ref().unwrap(), $options)
+    };
+}
+
+/// Returns an [`Encoder`] of `array`.
+pub(super) fn build_encoder<'a>(
+    array: &'a dyn Array,
+    options: &SortOptions,
+) -> Result<Encoder<'a>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => encode_boolean(array.as_any().downcast_ref().unwrap(), options),
+        Primitive(PrimitiveType::Int8) => primitive!(array, i8, options),
+        Primitive(PrimitiveType::Int16) => primitive!(array, i16, options),
+        Primitive(PrimitiveType::Int32) => primitive!(array, i32, options),
+        Primitive(PrimitiveType::Int64) => primitive!(array, i64, options),
+        Primitive(PrimitiveType::Int128) => primitive!(array, i128, options),
+        Primitive(PrimitiveType::UInt8) => primitive!(array, u8, options),
+        Primitive(PrimitiveType::UInt16) => primitive!(array, u16, options),
+        Primitive(PrimitiveType::UInt32) => primitive!(array, u32, options),
+        Primitive(PrimitiveType::UInt64) => primitive!(array, u64, options),
+        Primitive(PrimitiveType::Float32) => primitive!(array, f32, options),
+        Primitive(PrimitiveType::Float64) => primitive!(array, f64, options),
+        Utf8 => encode_utf8::<i32>(array.as_any().downcast_ref().unwrap(), options),
+        LargeUtf8 => encode_utf8::<i64>(array.as_any().downcast_ref().unwrap(), options),
+        Binary => encode_binary::<i32>(array.as_any().downcast_ref().unwrap(), options),
+        LargeBinary => encode_binary::<i64>(array.as_any().downcast_ref().unwrap(), options),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Row encoding of {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
@@ -0,0 +1,167 @@
+//! Contains functions to convert columns into rows of bytes whose lexicographic (byte) order
+//! is the order of the rows according to a list of [`SortOptions`], and back.
+//!
+//! This is useful for sorting and merging on multiple columns, since two rows can be
+//! compared via a single `memcmp` instead of dynamically dispatching on every column.
+//!
+//! # Format
+//! Each row is the concatenation of the encoding of each of its columns. Each column is
+//! encoded as:
+//! * a byte declaring the validity of the value (whose value depends on `nulls_first`)
+//! * the value, encoded in an order-preserving format:
+//!     * booleans as a single byte
+//!     * integers as big-endian with the sign bit flipped
+//!     * floats as their IEEE 754 total order, as big-endian
+//!     * variable-length values (utf8 and binary) with every `0x00` escaped as `0x00 0xFF`,
+//!       followed by the terminator `0x00 0x00`
+//!
+//! Fixed-length null values are padded with zeros so that every fixed-length column has the
+//! same width on all rows. Values of descending columns have all their bytes inverted.
+mod decoder;
+mod encoder;
+
+use crate::array::{Array, BinaryArray};
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+use super::sort::SortOptions;
+
+/// Encodes `columns` into rows of bytes, such that comparing two rows byte by byte
+/// is equivalent to a lexicographical comparison of the columns according to `options`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::row::encode;
+/// use arrow2::compute::sort::SortOptions;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let a = Int32Array::from_slice([2, 1, 1]);
+/// let b = Utf8Array::<i32>::from_slice(["a", "c", "b"]);
+///
+/// let options = [SortOptions::default(), SortOptions::default()];
+/// let rows = encode(&[&a, &b], &options)?;
+///
+/// assert!(rows.value(1) < rows.value(0));
+/// assert!(rows.value(2) < rows.value(1));
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors when:
+/// * `columns` and `options` have a different length
+/// * the columns have a different length
+/// * the [`DataType`] of any of the columns is not supported
+pub fn encode(columns: &[&dyn Array], options: &[SortOptions]) -> Result<BinaryArray<i64>> {
+    if columns.len() != options.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The number of columns and sort options must be equal".to_string(),
+        ));
+    }
+    let length = columns.first().map(|column| column.len()).unwrap_or(0);
+    if columns.iter().any(|column| column.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Row encoding requires all columns to have the same length".to_string(),
+        ));
+    }
+
+    let encoders = columns
+        .iter()
+        .zip(options.iter())
+        .map(|(column, options)| encoder::build_encoder(*column, options))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut values = Vec::<u8>::new();
+    let mut offsets = Vec::<i64>::with_capacity(length + 1);
+    offsets.push(0);
+    for row in 0..length {
+        for encoder in encoders.iter() {
+            encoder(&mut values, row);
+        }
+        offsets.push(values.len() as i64);
+    }
+
+    Ok(BinaryArray::<i64>::new(
+        DataType::LargeBinary,
+        offsets.into(),
+        values.into(),
+        None,
+    ))
+}
+
+/// Decodes `rows` created by [`encode`] back into columns, the inverse of [`encode`].
+/// `fields` and `options` must be the fields of the columns and the options used to encode them.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::row::{decode, encode};
+/// use arrow2::compute::sort::SortOptions;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let a = Int32Array::from(&[Some(2), None]);
+/// let b = Utf8Array::<i32>::from_slice(["a", "c"]);
+///
+/// let options = [SortOptions::default(), SortOptions::default()];
+/// let rows = encode(&[&a, &b], &options)?;
+///
+/// let fields = [
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ];
+/// let columns = decode(&rows, &fields, &options)?;
+/// assert_eq!(columns[0].as_ref(), &a as &dyn Array);
+/// assert_eq!(columns[1].as_ref(), &b as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors when:
+/// * `fields` and `options` have a different length
+/// * the [`DataType`] of any of the fields is not supported
+/// * the rows are not a valid encoding of `fields`
+pub fn decode(
+    rows: &BinaryArray<i64>,
+    fields: &[Field],
+    options: &[SortOptions],
+) -> Result<Vec<Box<dyn Array>>> {
+    if fields.len() != options.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The number of fields and sort options must be equal".to_string(),
+        ));
+    }
+
+    let mut rows = (0..rows.len()).map(|i| rows.value(i)).collect::<Vec<_>>();
+
+    let columns = fields
+        .iter()
+        .zip(options.iter())
+        .map(|(field, options)| decoder::decode_column(&mut rows, field.data_type(), options))
+        .collect::<Result<Vec<_>>>()?;
+
+    if rows.iter().any(|row| !row.is_empty()) {
+        return Err(ArrowError::oos(
+            "The row encoding is longer than declared by its fields",
+        ));
+    }
+    Ok(columns)
+}
+
+/// Returns the bytes declaring a (null, valid) value.
+#[inline]
+fn validity_markers(options: &SortOptions) -> (u8, u8) {
+    if options.nulls_first {
+        (0, 1)
+    } else {
+        (2, 1)
+    }
+}
+
+/// Returns the mask xored to every byte of a value.
+#[inline]
+fn descending_mask(options: &SortOptions) -> u8 {
+    if options.descending {
+        0xFF
+    } else {
+        0
+    }
+}
@@ -13,3 +13,18 @@ pub(super) fn indices_sorted_unstable_by<I: Index, O: Offset>(
     let cmp = |lhs: &&[u8], rhs: &&[u8]| lhs.cmp(rhs);
     common::indices_sorted_unstable_by(array.validity(), get, cmp, array.len(), options, limit)
 }
+
+/// Sorts a [`BinaryArray`] according to [`SortOptions`], returning at most `limit` items.
+/// When `limit` is smaller than the array's length, only the first `limit` items are sorted.
+pub(super) fn sort_by<O: Offset>(
+    array: &BinaryArray<O>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> BinaryArray<O> {
+    let limit = limit.unwrap_or_else(|| array.len()).min(array.len());
+
+    let values = array.iter().flatten().collect::<Vec<_>>();
+    let null_count = array.len() - values.len();
+    let cmp = |lhs: &&[u8], rhs: &&[u8]| lhs.cmp(rhs);
+    common::sorted_with_nulls(values, null_count, cmp, options, limit).collect()
+}
@@ -1,5 +1,6 @@
 use crate::{
-    array::{BooleanArray, PrimitiveArray},
+    array::{Array, BooleanArray, PrimitiveArray},
+    bitmap::MutableBitmap,
     types::Index,
 };
 
@@ -51,3 +52,57 @@ pub fn sort_boolean<I: Index>(
     let data_type = I::PRIMITIVE.into();
     PrimitiveArray::<I>::new(data_type, values.into(), None)
 }
+
+/// Sorts a [`BooleanArray`] according to [`SortOptions`], returning at most `limit` items.
+/// `false` is ordered before `true` in ascending order.
+/// # Implementation
+/// This function counts the number of `true`, `false` and null values and writes them
+/// in order, i.e. it is `O(N)` and does not compare values.
+pub(super) fn sort_by(
+    array: &BooleanArray,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> BooleanArray {
+    let length = array.len();
+    let limit = limit.unwrap_or(length).min(length);
+
+    let null_count = array.null_count();
+    let trues = match array.validity() {
+        Some(validity) => length - (array.values() & validity).null_count(),
+        None => length - array.values().null_count(),
+    };
+    let falses = length - null_count - trues;
+
+    let ((first, first_count), (second, second_count)) = if options.descending {
+        ((true, trues), (false, falses))
+    } else {
+        ((false, falses), (true, trues))
+    };
+    let valids = std::iter::repeat(first)
+        .take(first_count)
+        .chain(std::iter::repeat(second).take(second_count));
+    let valid_count = length - null_count;
+
+    let (values, validity) = if options.nulls_first {
+        let values = std::iter::repeat(false).take(null_count).chain(valids);
+        let validity = std::iter::repeat(false)
+            .take(null_count)
+            .chain(std::iter::repeat(true).take(valid_count));
+        (
+            MutableBitmap::from_trusted_len_iter(values.take(limit)),
+            MutableBitmap::from_trusted_len_iter(validity.take(limit)),
+        )
+    } else {
+        let values = valids.chain(std::iter::repeat(false).take(null_count));
+        let validity = std::iter::repeat(true)
+            .take(valid_count)
+            .chain(std::iter::repeat(false).take(null_count));
+        (
+            MutableBitmap::from_trusted_len_iter(values.take(limit)),
+            MutableBitmap::from_trusted_len_iter(validity.take(limit)),
+        )
+    };
+    let validity = (null_count > 0).then(|| validity.into());
+
+    BooleanArray::new(array.data_type().clone(), values.into(), validity)
+}
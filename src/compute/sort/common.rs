@@ -71,6 +71,62 @@ fn sort_unstable_by<I, T, G, F>(
     }
 }
 
+/// Sorts `values` according to `cmp` such that its first `limit` items are the first `limit`
+/// items of the sorted slice. The remaining items are left in an unspecified order.
+pub(super) fn sort_values_unstable_by<T, F>(
+    values: &mut [T],
+    mut cmp: F,
+    descending: bool,
+    limit: usize,
+) where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let mut compare = |lhs: &T, rhs: &T| {
+        if descending {
+            cmp(rhs, lhs)
+        } else {
+            cmp(lhs, rhs)
+        }
+    };
+    if limit < values.len() {
+        let (before, _, _) = values.select_nth_unstable_by(limit, &mut compare);
+        before.sort_unstable_by(compare);
+    } else {
+        values.sort_unstable_by(compare);
+    }
+}
+
+/// Returns the first `limit` items of the sorted sequence of `values` (the valid items)
+/// and `null_count` nulls, ordered according to `options`.
+pub(super) fn sorted_with_nulls<T, F>(
+    mut values: Vec<T>,
+    null_count: usize,
+    cmp: F,
+    options: &SortOptions,
+    limit: usize,
+) -> impl Iterator<Item = Option<T>>
+where
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    let values_limit = if options.nulls_first {
+        limit.saturating_sub(null_count)
+    } else {
+        limit
+    };
+    sort_values_unstable_by(&mut values, cmp, options.descending, values_limit);
+
+    let (leading_nulls, trailing_nulls) = if options.nulls_first {
+        (null_count, 0)
+    } else {
+        (0, null_count)
+    };
+    std::iter::repeat_with(|| None)
+        .take(leading_nulls)
+        .chain(values.into_iter().map(Some))
+        .chain(std::iter::repeat_with(|| None).take(trailing_nulls))
+        .take(limit)
+}
+
 /// # Safety
 /// This function guarantees that:
 /// * `get` is only called for `0 <= i < length`
@@ -164,3 +220,36 @@ where
     let data_type = I::PRIMITIVE.into();
     PrimitiveArray::<I>::new(data_type, indices.into(), None)
 }
+
+/// Returns whether the items of `iter` are sorted according to `cmp` and `options`,
+/// returning early on the first pair of items that is not.
+pub(super) fn is_sorted_by<T, I, F>(iter: I, mut cmp: F, options: &SortOptions) -> bool
+where
+    I: Iterator<Item = Option<T>>,
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    use std::cmp::Ordering;
+    let null_ordering = if options.nulls_first {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+
+    let mut previous: Option<Option<T>> = None;
+    for item in iter {
+        if let Some(previous) = &previous {
+            let ordering = match (previous, &item) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => null_ordering,
+                (Some(_), None) => null_ordering.reverse(),
+                (Some(lhs), Some(rhs)) if options.descending => cmp(rhs, lhs),
+                (Some(lhs), Some(rhs)) => cmp(lhs, rhs),
+            };
+            if ordering == Ordering::Greater {
+                return false;
+            }
+        }
+        previous = Some(item);
+    }
+    true
+}
@@ -0,0 +1,39 @@
+use crate::array::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray, PrimitiveArray};
+use crate::types::Index;
+
+use super::common;
+use super::SortOptions;
+
+pub(super) fn indices_sorted_unstable_by<I: Index>(
+    array: &FixedSizeBinaryArray,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> PrimitiveArray<I> {
+    let get = |idx: usize| unsafe { array.value_unchecked(idx) };
+    let cmp = |lhs: &&[u8], rhs: &&[u8]| lhs.cmp(rhs);
+    common::indices_sorted_unstable_by(array.validity(), get, cmp, array.len(), options, limit)
+}
+
+/// Sorts a [`FixedSizeBinaryArray`] lexicographically according to [`SortOptions`], returning
+/// at most `limit` items.
+/// When `limit` is smaller than the array's length, only the first `limit` items are sorted.
+pub(super) fn sort_by(
+    array: &FixedSizeBinaryArray,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> FixedSizeBinaryArray {
+    let limit = limit.unwrap_or_else(|| array.len()).min(array.len());
+
+    let values = array.iter().flatten().collect::<Vec<_>>();
+    let null_count = array.len() - values.len();
+    let cmp = |lhs: &&[u8], rhs: &&[u8]| lhs.cmp(rhs);
+
+    let mut sorted = MutableFixedSizeBinaryArray::from_data(
+        array.data_type().clone(),
+        Vec::with_capacity(limit * array.size()),
+        None,
+    );
+    common::sorted_with_nulls(values, null_count, cmp, options, limit)
+        .for_each(|value| sorted.push(value));
+    sorted.into()
+}
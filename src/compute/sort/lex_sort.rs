@@ -7,7 +7,7 @@ use crate::{
     types::Index,
 };
 
-use super::{sort_to_indices, SortOptions};
+use super::SortOptions;
 use crate::array::ord::DynComparator;
 
 type IsValid = Box<dyn Fn(usize) -> bool + Send + Sync>;
@@ -124,6 +124,9 @@ pub(crate) fn build_compare(array: &dyn Array, sort_option: SortOptions) -> Resu
 
 /// Sorts a list of [`SortColumn`] into a non-nullable [`PrimitiveArray`]
 /// representing the indices that would sort the columns.
+///
+/// Ties on a column fall through to the next column. Rows that are equal on all columns
+/// keep their relative order, i.e. the sort is stable (also for a single column).
 pub fn lexsort_to_indices<I: Index>(
     columns: &[SortColumn],
     limit: Option<usize>,
@@ -133,12 +136,6 @@ pub fn lexsort_to_indices<I: Index>(
             "Sort requires at least one column".to_string(),
         ));
     }
-    if columns.len() == 1 {
-        // fallback to non-lexical sort
-        let column = &columns[0];
-        return sort_to_indices(column.values, &column.options.unwrap_or_default(), limit);
-    }
-
     let row_count = columns[0].values.len();
     if columns.iter().any(|item| item.values.len() != row_count) {
         return Err(ArrowError::InvalidArgumentError(
@@ -164,7 +161,8 @@ pub fn lexsort_to_indices<I: Index>(
             }
         }
 
-        Ordering::Equal
+        // ties are ordered by their position, so that the (unstable) sort is stable
+        a_idx.cmp(&b_idx)
     };
 
     let mut values = I::range(0, row_count).unwrap().collect::<Vec<_>>();
@@ -13,12 +13,18 @@ use crate::{
 mod binary;
 mod boolean;
 mod common;
+mod fixed_size_binary;
 mod lex_sort;
 mod primitive;
+mod rank;
 mod utf8;
 
 pub(crate) use lex_sort::build_compare;
 pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
+pub use primitive::{
+    indices_sorted_stable_by, is_sorted_by, sort_owned_by, sort_stable_by, top_k_indices_by,
+};
+pub use rank::{rank, RankMethod};
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -44,6 +50,11 @@ pub fn sort(
     limit: Option<usize>,
 ) -> Result<Box<dyn Array>> {
     match values.data_type() {
+        DataType::Boolean => Ok(Box::new(boolean::sort_by(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        ))),
         DataType::Int8 => dyn_sort!(i8, values, ord::total_cmp, options, limit),
         DataType::Int16 => dyn_sort!(i16, values, ord::total_cmp, options, limit),
         DataType::Int32
@@ -63,6 +74,31 @@ pub fn sort(
         DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options, limit),
         DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options, limit),
+        DataType::Utf8 => Ok(Box::new(utf8::sort_by::<i32>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        ))),
+        DataType::LargeUtf8 => Ok(Box::new(utf8::sort_by::<i64>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        ))),
+        DataType::Binary => Ok(Box::new(binary::sort_by::<i32>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        ))),
+        DataType::LargeBinary => Ok(Box::new(binary::sort_by::<i64>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        ))),
+        DataType::FixedSizeBinary(_) => Ok(Box::new(fixed_size_binary::sort_by(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        ))),
         _ => {
             let indices = sort_to_indices::<u64>(values, options, limit)?;
             take::take(values, &indices)
@@ -151,6 +187,11 @@ pub fn sort_to_indices<I: Index>(
             options,
             limit,
         )),
+        DataType::FixedSizeBinary(_) => Ok(fixed_size_binary::indices_sorted_unstable_by::<I>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        )),
         DataType::List(field) => {
             let (v, n) = partition_validity(values);
             match &field.data_type {
@@ -232,6 +273,23 @@ fn sort_dict<I: Index, O: Offset>(
     })
 }
 
+/// Returns whether an [`Array`] is sorted according to [`SortOptions`], i.e. whether
+/// [`sort`] would return an equal array.
+///
+/// Values are compared as in [`crate::array::ord::build_compare`] and nulls are ordered
+/// according to the `nulls_first` flag in `options`.
+/// # Errors
+/// Errors if the [`DataType`] has no order relationship.
+pub fn is_sorted(values: &dyn Array, options: &SortOptions) -> Result<bool> {
+    let cmp = ord::build_compare(values, values)?;
+    let iter = (0..values.len()).map(|index| values.is_valid(index).then(|| index));
+    Ok(common::is_sorted_by(
+        iter,
+        |lhs, rhs| cmp(*lhs, *rhs),
+        options,
+    ))
+}
+
 /// Checks if an array of type `datatype` can be sorted
 ///
 /// # Examples
@@ -268,7 +326,8 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::Utf8
         | DataType::LargeUtf8
         | DataType::Binary
-        | DataType::LargeBinary => true,
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => true,
         DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
             matches!(
                 field.data_type(),
@@ -1,5 +1,8 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
 use crate::{
-    array::PrimitiveArray,
+    array::{Array, PrimitiveArray},
     types::{Index, NativeType},
 };
 
@@ -31,6 +34,153 @@ where
     }
 }
 
+/// Stable sort of indices: indices of values for which `cmp` returns
+/// [`std::cmp::Ordering::Equal`] (and of nulls) are kept in their original order.
+/// # Implementation
+/// This uses a stable sort, which allocates auxiliary memory and is usually slower than
+/// [`indices_sorted_unstable_by`]. `limit` only truncates the result.
+pub fn indices_sorted_stable_by<I, T, F>(
+    array: &PrimitiveArray<T>,
+    cmp: F,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> PrimitiveArray<I>
+where
+    I: Index,
+    T: NativeType,
+    F: Fn(&T, &T) -> std::cmp::Ordering,
+{
+    let length = array.len();
+    let limit = limit.unwrap_or(length).min(length);
+    let values = array.values().as_slice();
+
+    let (mut valids, nulls): (Vec<I>, Vec<I>) = I::range(0, length)
+        .unwrap()
+        .partition(|index| array.is_valid(index.to_usize()));
+
+    let get = |index: &I| &values[index.to_usize()];
+    if options.descending {
+        valids.sort_by(|lhs, rhs| cmp(get(rhs), get(lhs)));
+    } else {
+        valids.sort_by(|lhs, rhs| cmp(get(lhs), get(rhs)));
+    }
+
+    let mut indices = if options.nulls_first {
+        nulls
+            .into_iter()
+            .chain(valids)
+            .take(limit)
+            .collect::<Vec<_>>()
+    } else {
+        valids
+            .into_iter()
+            .chain(nulls)
+            .take(limit)
+            .collect::<Vec<_>>()
+    };
+    indices.shrink_to_fit();
+
+    let data_type = I::PRIMITIVE.into();
+    PrimitiveArray::<I>::new(data_type, indices.into(), None)
+}
+
+/// An item of the bounded heap of [`top_k_indices_by`], ordered by value and then by index.
+struct HeapItem<'a, T> {
+    value: T,
+    index: usize,
+    cmp: &'a dyn Fn(&T, &T) -> Ordering,
+}
+
+impl<T> PartialEq for HeapItem<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for HeapItem<'_, T> {}
+
+impl<T> PartialOrd for HeapItem<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for HeapItem<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.cmp)(&self.value, &other.value).then(self.index.cmp(&other.index))
+    }
+}
+
+/// Returns the indices of the first `k` values of `array` sorted according to `cmp` and `options`,
+/// i.e. of its `k` smallest (or largest, when `descending`) values, in sorted order.
+/// Indices of equal values (and of nulls) are in their original order.
+/// # Implementation
+/// This keeps the `k` first values in a bounded heap and is `O(n log k)`. It is faster than
+/// [`indices_sorted_unstable_by`] with a `limit` when `k` is small compared to the length of `array`.
+pub fn top_k_indices_by<I, T, F>(
+    array: &PrimitiveArray<T>,
+    cmp: F,
+    k: usize,
+    options: &SortOptions,
+) -> PrimitiveArray<I>
+where
+    I: Index,
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let length = array.len();
+    let k = k.min(length);
+    let values = array.values().as_slice();
+
+    let nulls = || (0..length).filter(|index| !array.is_valid(*index));
+
+    let mut indices = Vec::<usize>::with_capacity(k);
+    if options.nulls_first {
+        indices.extend(nulls().take(k));
+    }
+
+    let remaining = k - indices.len();
+    let cmp = |lhs: &T, rhs: &T| {
+        if options.descending {
+            cmp(rhs, lhs)
+        } else {
+            cmp(lhs, rhs)
+        }
+    };
+    // a max-heap whose top is the last of the values kept so far
+    let mut heap = BinaryHeap::with_capacity(remaining);
+    if remaining > 0 {
+        for index in (0..length).filter(|index| array.is_valid(*index)) {
+            let item = HeapItem {
+                value: values[index],
+                index,
+                cmp: &cmp,
+            };
+            if heap.len() < remaining {
+                heap.push(item);
+            } else if let Some(mut top) = heap.peek_mut() {
+                if item < *top {
+                    *top = item;
+                }
+            }
+        }
+    }
+    indices.extend(heap.into_sorted_vec().into_iter().map(|item| item.index));
+
+    if !options.nulls_first {
+        let remaining = k - indices.len();
+        indices.extend(nulls().take(remaining));
+    }
+
+    let indices = indices
+        .into_iter()
+        .map(|index| I::from_usize(index).unwrap())
+        .collect::<Vec<_>>();
+
+    let data_type = I::PRIMITIVE.into();
+    PrimitiveArray::<I>::new(data_type, indices.into(), None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,4 +367,81 @@ mod tests {
             &[2, 1, 4, 3, 0],
         );
     }
+
+    #[test]
+    fn stable() {
+        let input = PrimitiveArray::<i8>::from(&[Some(3), None, Some(1), Some(3), None, Some(1)]);
+        let cases = [
+            (false, false, vec![2, 5, 0, 3, 1, 4]),
+            (false, true, vec![1, 4, 2, 5, 0, 3]),
+            (true, false, vec![0, 3, 2, 5, 1, 4]),
+            (true, true, vec![1, 4, 0, 3, 2, 5]),
+        ];
+        for (descending, nulls_first, expected) in cases {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            let output =
+                indices_sorted_stable_by::<i32, _, _>(&input, ord::total_cmp, &options, None);
+            assert_eq!(output, Int32Array::from_vec(expected.clone()));
+
+            let output =
+                indices_sorted_stable_by::<i32, _, _>(&input, ord::total_cmp, &options, Some(3));
+            assert_eq!(output, Int32Array::from_slice(&expected[..3]));
+        }
+    }
+
+    #[test]
+    fn top_k() {
+        let input = Int32Array::from(&[Some(3), None, Some(1), Some(5), Some(3), None, Some(2)]);
+        let top_k = |k, descending, nulls_first| {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            top_k_indices_by::<i32, _, _>(&input, ord::total_cmp, k, &options)
+        };
+
+        assert_eq!(top_k(3, false, false), Int32Array::from_slice(&[2, 6, 0]));
+        assert_eq!(top_k(3, true, false), Int32Array::from_slice(&[3, 0, 4]));
+        assert_eq!(top_k(3, false, true), Int32Array::from_slice(&[1, 5, 2]));
+        assert_eq!(top_k(0, false, true), Int32Array::from_slice(&[]));
+        assert_eq!(
+            top_k(10, false, false),
+            Int32Array::from_slice(&[2, 6, 0, 4, 3, 1, 5])
+        );
+    }
+
+    #[test]
+    fn top_k_equals_limited_sort() {
+        let input = (0..100)
+            .map(|x| {
+                if x % 7 == 0 {
+                    None
+                } else {
+                    Some((x * 37) % 23)
+                }
+            })
+            .collect::<Int32Array>();
+
+        for descending in [false, true] {
+            for nulls_first in [false, true] {
+                let options = SortOptions {
+                    descending,
+                    nulls_first,
+                };
+                for k in [1, 5, 20, 100] {
+                    let expected = indices_sorted_stable_by::<i32, _, _>(
+                        &input,
+                        ord::total_cmp,
+                        &options,
+                        Some(k),
+                    );
+                    let result = top_k_indices_by::<i32, _, _>(&input, ord::total_cmp, k, &options);
+                    assert_eq!(result, expected);
+                }
+            }
+        }
+    }
 }
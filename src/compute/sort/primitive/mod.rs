@@ -1,5 +1,5 @@
 mod indices;
 mod sort;
 
-pub use indices::indices_sorted_unstable_by;
-pub use sort::sort_by;
+pub use indices::{indices_sorted_stable_by, indices_sorted_unstable_by, top_k_indices_by};
+pub use sort::{is_sorted_by, sort_by, sort_owned_by, sort_stable_by};
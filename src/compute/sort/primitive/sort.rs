@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use either::Either;
+
 use crate::bitmap::Bitmap;
 use crate::buffer::Buffer;
 use crate::{
@@ -23,6 +25,7 @@ use crate::{
     types::NativeType,
 };
 
+use super::super::common;
 use super::super::SortOptions;
 
 /// # Safety
@@ -42,11 +45,21 @@ where
     }
 }
 
-fn sort_values<T, F>(values: &mut [T], mut cmp: F, descending: bool, limit: usize)
+fn sort_values<T, F>(values: &mut [T], mut cmp: F, descending: bool, limit: usize, stable: bool)
 where
     T: NativeType,
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
+    if stable {
+        // a stable partial sort is not available; sort all values and let the caller truncate
+        if descending {
+            values.sort_by(|x, y| cmp(y, x));
+        } else {
+            values.sort_by(cmp);
+        };
+        return;
+    }
+
     if limit != values.len() {
         return k_element_sort_inner(values, descending, limit, cmp);
     }
@@ -64,6 +77,7 @@ fn sort_nullable<T, F>(
     cmp: F,
     options: &SortOptions,
     limit: usize,
+    stable: bool,
 ) -> (Buffer<T>, Option<Bitmap>)
 where
     T: NativeType,
@@ -99,6 +113,7 @@ where
             cmp,
             options.descending,
             limit - validity.null_count(),
+            stable,
         );
     } else {
         // validity is [1,1,1,...,0,0,0,0]
@@ -115,6 +130,7 @@ where
             cmp,
             options.descending,
             limit - validity.null_count(),
+            stable,
         );
 
         if limit > values.len() - validity.null_count() {
@@ -136,6 +152,75 @@ pub fn sort_by<T, F>(
     options: &SortOptions,
     limit: Option<usize>,
 ) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    sort(array, cmp, options, limit, false)
+}
+
+/// Sorts a [`PrimitiveArray`] according to `cmp` comparator and [`SortOptions`], keeping
+/// values for which `cmp` returns [`std::cmp::Ordering::Equal`] in their original order.
+/// # Implementation
+/// This uses a stable sort, which allocates auxiliary memory and is usually slower than
+/// [`sort_by`]. Furthermore, `limit` only truncates the result: all values are always sorted.
+pub fn sort_stable_by<T, F>(
+    array: &PrimitiveArray<T>,
+    cmp: F,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    sort(array, cmp, options, limit, true)
+}
+
+/// Sorts an owned [`PrimitiveArray`] according to `cmp` comparator and [`SortOptions`].
+///
+/// When `array` has no validity and its values are not shared (see [`Buffer::into_mut`]),
+/// they are sorted in place, which avoids copying all values when `limit` is small.
+/// Otherwise, this is equivalent to [`sort_by`].
+pub fn sort_owned_by<T, F>(
+    array: PrimitiveArray<T>,
+    cmp: F,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    if array.validity().is_some() {
+        return sort_by(&array, cmp, options, limit);
+    }
+    let limit = limit.unwrap_or_else(|| array.len()).min(array.len());
+
+    let (data_type, values, _) = array.into_data();
+    let len = values.len();
+    match values.into_mut() {
+        Either::Right(mut values) => {
+            // the buffer may be a slice `(0, len)` of a longer vector
+            values.truncate(len);
+            sort_values(values.as_mut_slice(), cmp, options.descending, limit, false);
+            values.truncate(limit);
+            PrimitiveArray::<T>::new(data_type, values.into(), None)
+        }
+        Either::Left(values) => {
+            let array = PrimitiveArray::<T>::new(data_type, values, None);
+            sort_by(&array, cmp, options, Some(limit))
+        }
+    }
+}
+
+fn sort<T, F>(
+    array: &PrimitiveArray<T>,
+    cmp: F,
+    options: &SortOptions,
+    limit: Option<usize>,
+    stable: bool,
+) -> PrimitiveArray<T>
 where
     T: NativeType,
     F: FnMut(&T, &T) -> std::cmp::Ordering,
@@ -147,12 +232,18 @@ where
     let validity = array.validity();
 
     let (buffer, validity) = if let Some(validity) = validity {
-        sort_nullable(values, validity, cmp, options, limit)
+        sort_nullable(values, validity, cmp, options, limit, stable)
     } else {
         let mut buffer = Vec::<T>::new();
         buffer.extend_from_slice(values);
 
-        sort_values(buffer.as_mut_slice(), cmp, options.descending, limit);
+        sort_values(
+            buffer.as_mut_slice(),
+            cmp,
+            options.descending,
+            limit,
+            stable,
+        );
         buffer.truncate(limit);
         buffer.shrink_to_fit();
 
@@ -161,6 +252,18 @@ where
     PrimitiveArray::<T>::new(array.data_type().clone(), buffer, validity)
 }
 
+/// Returns whether a [`PrimitiveArray`] is sorted according to `cmp` comparator and
+/// [`SortOptions`], i.e. whether [`sort_by`] would return the same values.
+/// # Implementation
+/// This performs a single pass over the array, returning on the first out of order item.
+pub fn is_sorted_by<T, F>(array: &PrimitiveArray<T>, mut cmp: F, options: &SortOptions) -> bool
+where
+    T: NativeType,
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
+{
+    common::is_sorted_by(array.iter(), |lhs, rhs| cmp(*lhs, *rhs), options)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,4 +342,85 @@ mod tests {
             &[Some(5), Some(3), Some(3), Some(2), None, None],
         );
     }
+
+    #[test]
+    fn stable() {
+        // compare only the tens, so that e.g. 12 and 15 are equal
+        let cmp = |lhs: &i32, rhs: &i32| (lhs / 10).cmp(&(rhs / 10));
+        let input = PrimitiveArray::<i32>::from(&[Some(15), None, Some(3), Some(12), Some(1)]);
+
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let output = sort_stable_by(&input, cmp, &options, None);
+        let expected = PrimitiveArray::<i32>::from(&[Some(3), Some(1), Some(15), Some(12), None]);
+        assert_eq!(output, expected);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let output = sort_stable_by(&input, cmp, &options, Some(3));
+        let expected = PrimitiveArray::<i32>::from(&[None, Some(15), Some(12)]);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn owned() {
+        let options = SortOptions {
+            descending: false,
+            nulls_first: true,
+        };
+        let input = PrimitiveArray::<i32>::from_vec(vec![5, 3, 9, 1, 7]);
+        let ptr = input.values().as_ptr();
+        let output = sort_owned_by(input, ord::total_cmp, &options, Some(2));
+        assert_eq!(output, PrimitiveArray::<i32>::from_vec(vec![1, 3]));
+        // sorted in place
+        assert_eq!(output.values().as_ptr(), ptr);
+
+        // shared values are not mutated
+        let input = PrimitiveArray::<i32>::from_vec(vec![5, 3, 9, 1, 7]);
+        let shared = input.clone();
+        let output = sort_owned_by(input, ord::total_cmp, &options, None);
+        assert_eq!(output, PrimitiveArray::<i32>::from_vec(vec![1, 3, 5, 7, 9]));
+        assert_eq!(shared, PrimitiveArray::<i32>::from_vec(vec![5, 3, 9, 1, 7]));
+
+        let input = PrimitiveArray::<i32>::from(&[Some(2), None, Some(1)]);
+        let output = sort_owned_by(input, ord::total_cmp, &options, None);
+        assert_eq!(
+            output,
+            PrimitiveArray::<i32>::from(&[None, Some(1), Some(2)])
+        );
+
+        // a slice `(0, 2)` whose parent was dropped owns the whole vector
+        let input = PrimitiveArray::<i32>::from_vec(vec![5, 3, 9, 1, 7]).slice(0, 2);
+        let output = sort_owned_by(input, ord::total_cmp, &options, None);
+        assert_eq!(output, PrimitiveArray::<i32>::from_vec(vec![3, 5]));
+    }
+
+    #[test]
+    fn is_sorted() {
+        let input = PrimitiveArray::<i32>::from(&[None, Some(3), Some(5), Some(2), Some(3), None]);
+        for descending in [false, true] {
+            for nulls_first in [false, true] {
+                let options = SortOptions {
+                    descending,
+                    nulls_first,
+                };
+                assert!(!is_sorted_by(&input, ord::total_cmp, &options));
+                let sorted = sort_by(&input, ord::total_cmp, &options, None);
+                assert!(is_sorted_by(&sorted, ord::total_cmp, &options));
+            }
+        }
+
+        let options = SortOptions {
+            descending: false,
+            nulls_first: true,
+        };
+        let input = PrimitiveArray::<i32>::from(&[Some(1), None]);
+        assert!(!is_sorted_by(&input, ord::total_cmp, &options));
+        let input = PrimitiveArray::<i32>::from_slice(&[]);
+        assert!(is_sorted_by(&input, ord::total_cmp, &options));
+    }
 }
@@ -0,0 +1,71 @@
+use crate::{
+    array::{Array, PrimitiveArray},
+    datatypes::DataType,
+    types::NativeType,
+};
+
+use super::{primitive::indices_sorted_unstable_by, SortOptions};
+
+/// How [`rank`] assigns ranks to equal values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RankMethod {
+    /// Equal values get the same rank and the next distinct value gets the next rank
+    /// (e.g. `1, 2, 2, 3`).
+    Dense,
+    /// Equal values get the lowest rank of their group (e.g. `1, 2, 2, 4`).
+    Min,
+    /// Equal values get the highest rank of their group (e.g. `1, 3, 3, 4`).
+    Max,
+    /// Equal values get the average rank of their group (e.g. `1, 2.5, 2.5, 4`).
+    Average,
+}
+
+/// Returns the 1-based rank of each value of `array` when sorted according to `cmp` and `options`,
+/// with ties resolved according to `method`.
+///
+/// Null values are not ranked and have a null rank.
+/// The ranks are [`f64`] so that [`RankMethod::Average`] can represent fractional ranks.
+pub fn rank<T, F>(
+    array: &PrimitiveArray<T>,
+    cmp: F,
+    method: RankMethod,
+    options: &SortOptions,
+) -> PrimitiveArray<f64>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> std::cmp::Ordering,
+{
+    let values = array.values().as_slice();
+    let indices = indices_sorted_unstable_by::<u32, T, _>(array, &cmp, options, None);
+    let indices = indices
+        .values()
+        .iter()
+        .map(|index| *index as usize)
+        .filter(|index| array.is_valid(*index))
+        .collect::<Vec<_>>();
+
+    let mut ranks = vec![0.0; array.len()];
+    let mut dense = 0;
+    let mut start = 0;
+    while start < indices.len() {
+        let value = &values[indices[start]];
+        let end = start
+            + indices[start..]
+                .iter()
+                .take_while(|index| cmp(&values[**index], value).is_eq())
+                .count();
+        dense += 1;
+        let rank = match method {
+            RankMethod::Dense => dense as f64,
+            RankMethod::Min => (start + 1) as f64,
+            RankMethod::Max => end as f64,
+            RankMethod::Average => (start + 1 + end) as f64 / 2.0,
+        };
+        indices[start..end]
+            .iter()
+            .for_each(|index| ranks[*index] = rank);
+        start = end;
+    }
+
+    PrimitiveArray::<f64>::new(DataType::Float64, ranks.into(), array.validity().cloned())
+}
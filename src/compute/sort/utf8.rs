@@ -15,6 +15,21 @@ pub(super) fn indices_sorted_unstable_by<I: Index, O: Offset>(
     common::indices_sorted_unstable_by(array.validity(), get, cmp, array.len(), options, limit)
 }
 
+/// Sorts a [`Utf8Array`] according to [`SortOptions`], returning at most `limit` items.
+/// When `limit` is smaller than the array's length, only the first `limit` items are sorted.
+pub(super) fn sort_by<O: Offset>(
+    array: &Utf8Array<O>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Utf8Array<O> {
+    let limit = limit.unwrap_or_else(|| array.len()).min(array.len());
+
+    let values = array.iter().flatten().collect::<Vec<_>>();
+    let null_count = array.len() - values.len();
+    let cmp = |lhs: &&str, rhs: &&str| lhs.cmp(rhs);
+    common::sorted_with_nulls(values, null_count, cmp, options, limit).collect()
+}
+
 pub(super) fn indices_sorted_unstable_by_dictionary<I: Index, K: DictionaryKey, O: Offset>(
     array: &DictionaryArray<K>,
     options: &SortOptions,
@@ -1,7 +1,9 @@
 //! Defines common maps to a [`Utf8Array`]
 
 use crate::{
-    array::{Array, Offset, Utf8Array},
+    array::{Array, BooleanArray, Int32Array, Offset, Utf8Array},
+    bitmap::Bitmap,
+    compute::utils::combine_validities,
     datatypes::DataType,
     error::{ArrowError, Result},
 };
@@ -14,6 +16,148 @@ pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>)
     new.with_validity(array.validity().cloned())
 }
 
+/// Returns the number of non-overlapping occurrences of `needle` in each element of `array`.
+/// An empty `needle` has 0 occurrences. Nulls remain nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::utf8::count_matches;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("banana"), None, Some("aaa")]);
+/// let result = count_matches(&array, "a");
+/// assert_eq!(result, Int32Array::from(&[Some(3), None, Some(3)]));
+/// ```
+pub fn count_matches<O: Offset>(array: &Utf8Array<O>, needle: &str) -> Int32Array {
+    let iter = array.values_iter().map(|x| {
+        if needle.is_empty() {
+            0
+        } else {
+            x.matches(needle).count() as i32
+        }
+    });
+
+    Int32Array::from_trusted_len_values_iter(iter).with_validity(array.validity().cloned())
+}
+
+fn split_part_str<'a>(value: &'a str, delimiter: &str, n: i32) -> &'a str {
+    if delimiter.is_empty() {
+        return if n == 1 || n == -1 { value } else { "" };
+    }
+    let index = n.unsigned_abs() as usize;
+    match n.cmp(&0) {
+        std::cmp::Ordering::Greater => value.split(delimiter).nth(index - 1).unwrap_or(""),
+        std::cmp::Ordering::Less => value.rsplit(delimiter).nth(index - 1).unwrap_or(""),
+        std::cmp::Ordering::Equal => "",
+    }
+}
+
+/// Splits each element of `array` by `delimiter` and returns its `n`th field, like SQL's `split_part`.
+///
+/// `n` is 1-based, and a negative `n` counts from the end (`-1` is the last field).
+/// Out of range fields (including `n == 0`) are empty strings and nulls remain nulls.
+/// An empty `delimiter` does not split the elements, i.e. each element is its only field.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::split_part;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a.b.c"), None, Some("d")]);
+/// let result = split_part(&array, ".", 2);
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("b"), None, Some("")]));
+/// ```
+pub fn split_part<O: Offset>(array: &Utf8Array<O>, delimiter: &str, n: i32) -> Utf8Array<O> {
+    let iter = array.values_iter().map(|x| split_part_str(x, delimiter, n));
+
+    let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+fn predicate<O: Offset, F: Fn(&str, &str) -> bool>(
+    array: &Utf8Array<O>,
+    patterns: &Utf8Array<O>,
+    op: F,
+) -> Result<BooleanArray> {
+    if array.len() != patterns.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot perform comparison operation on arrays of different length".to_string(),
+        ));
+    }
+    let validity = combine_validities(array.validity(), patterns.validity());
+
+    let values = array
+        .values_iter()
+        .zip(patterns.values_iter())
+        .map(|(x, pattern)| op(x, pattern));
+    let values = Bitmap::from_trusted_len_iter(values);
+    Ok(BooleanArray::new(DataType::Boolean, values, validity))
+}
+
+fn predicate_scalar<O: Offset, F: Fn(&str) -> bool>(array: &Utf8Array<O>, op: F) -> BooleanArray {
+    let values = Bitmap::from_trusted_len_iter(array.values_iter().map(op));
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Returns whether each element of `array` contains the pattern of the same row of `patterns`.
+/// The result is null when either the element or the pattern is null.
+/// # Error
+/// Errors iff the arrays have a different length.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::utf8::contains;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("arrow"), Some("arrow"), None]);
+/// let patterns = Utf8Array::<i32>::from(&[Some("rr"), Some("x"), Some("a")]);
+/// let result = contains(&array, &patterns).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+/// ```
+pub fn contains<O: Offset>(array: &Utf8Array<O>, patterns: &Utf8Array<O>) -> Result<BooleanArray> {
+    predicate(array, patterns, |x, pattern| x.contains(pattern))
+}
+
+/// Returns whether each element of `array` starts with the pattern of the same row of `patterns`.
+/// The result is null when either the element or the pattern is null.
+/// # Error
+/// Errors iff the arrays have a different length.
+pub fn starts_with<O: Offset>(
+    array: &Utf8Array<O>,
+    patterns: &Utf8Array<O>,
+) -> Result<BooleanArray> {
+    predicate(array, patterns, |x, pattern| x.starts_with(pattern))
+}
+
+/// Returns whether each element of `array` ends with the pattern of the same row of `patterns`.
+/// The result is null when either the element or the pattern is null.
+/// # Error
+/// Errors iff the arrays have a different length.
+pub fn ends_with<O: Offset>(array: &Utf8Array<O>, patterns: &Utf8Array<O>) -> Result<BooleanArray> {
+    predicate(array, patterns, |x, pattern| x.ends_with(pattern))
+}
+
+/// Returns whether each element of `array` contains `pattern`. Nulls remain nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::utf8::contains_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("arrow"), Some("parquet"), None]);
+/// let result = contains_scalar(&array, "rr");
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+/// ```
+pub fn contains_scalar<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> BooleanArray {
+    predicate_scalar(array, |x| x.contains(pattern))
+}
+
+/// Returns whether each element of `array` starts with `pattern`. Nulls remain nulls.
+pub fn starts_with_scalar<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> BooleanArray {
+    predicate_scalar(array, |x| x.starts_with(pattern))
+}
+
+/// Returns whether each element of `array` ends with `pattern`. Nulls remain nulls.
+pub fn ends_with_scalar<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> BooleanArray {
+    predicate_scalar(array, |x| x.ends_with(pattern))
+}
+
 /// Returns a new `Array` where each of each of the elements is upper-cased.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
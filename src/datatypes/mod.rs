@@ -7,7 +7,7 @@ mod schema;
 
 pub use field::Field;
 pub use physical_type::*;
-pub use schema::Schema;
+pub use schema::{schema_diff, Schema, SchemaDifference};
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -154,6 +154,10 @@ pub enum DataType {
     /// scale is the number of decimal places.
     /// The number 999.99 has a precision of 5 and scale of 2.
     Decimal(usize, usize),
+    /// Decimal value with precision and scale backed by a 256-bit integer.
+    /// Values are represented as 32-byte, little-endian, two's complement integers,
+    /// whose physical type is [`PhysicalType::FixedSizeBinary`].
+    Decimal256(usize, usize),
     /// Extension type.
     Extension(String, Box<DataType>, Option<String>),
 }
@@ -245,7 +249,7 @@ impl DataType {
                 PhysicalType::Primitive(PrimitiveType::MonthDayNano)
             }
             Binary => PhysicalType::Binary,
-            FixedSizeBinary(_) => PhysicalType::FixedSizeBinary,
+            FixedSizeBinary(_) | Decimal256(_, _) => PhysicalType::FixedSizeBinary,
             LargeBinary => PhysicalType::LargeBinary,
             Utf8 => PhysicalType::Utf8,
             LargeUtf8 => PhysicalType::LargeUtf8,
@@ -36,3 +36,65 @@ impl From<Vec<Field>> for Schema {
         }
     }
 }
+
+/// A difference between two [`Schema`]s, as returned by [`schema_diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaDifference {
+    /// A field of the second schema without a field of the same name in the first schema
+    Added(Field),
+    /// A field of the first schema without a field of the same name in the second schema
+    Removed(Field),
+    /// A field whose data type, nullability or metadata differs between the two schemas
+    Changed {
+        /// The field in the first schema
+        before: Field,
+        /// The field in the second schema
+        after: Field,
+    },
+}
+
+/// Returns the differences between the fields of `before` and `after`, matching fields by name.
+///
+/// Removed and changed fields are reported in the order of `before`, followed by the added
+/// fields in the order of `after`. The order of the fields and the schemas' metadata are
+/// not compared.
+/// # Example
+/// ```
+/// use arrow2::datatypes::{schema_diff, DataType, Field, Schema, SchemaDifference};
+///
+/// let before = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+/// let after = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+/// assert_eq!(
+///     schema_diff(&before, &after),
+///     vec![SchemaDifference::Changed {
+///         before: before.fields[0].clone(),
+///         after: after.fields[0].clone(),
+///     }]
+/// );
+/// ```
+pub fn schema_diff(before: &Schema, after: &Schema) -> Vec<SchemaDifference> {
+    let find = |fields: &[Field], name: &str| fields.iter().find(|f| f.name == name).cloned();
+
+    let mut differences = before
+        .fields
+        .iter()
+        .filter_map(|field| match find(&after.fields, &field.name) {
+            None => Some(SchemaDifference::Removed(field.clone())),
+            Some(other) if &other != field => Some(SchemaDifference::Changed {
+                before: field.clone(),
+                after: other,
+            }),
+            Some(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    differences.extend(
+        after
+            .fields
+            .iter()
+            .filter(|field| find(&before.fields, &field.name).is_none())
+            .cloned()
+            .map(SchemaDifference::Added),
+    );
+    differences
+}
@@ -260,11 +260,8 @@ fn buffer_offset(array: &ArrowArray, data_type: &DataType, i: usize) -> usize {
 fn buffer_len(array: &ArrowArray, data_type: &DataType, i: usize) -> Result<usize> {
     Ok(match (data_type.to_physical_type(), i) {
         (PhysicalType::FixedSizeBinary, 1) => {
-            if let DataType::FixedSizeBinary(size) = data_type.to_logical_type() {
-                *size * (array.offset as usize + array.length as usize)
-            } else {
-                unreachable!()
-            }
+            FixedSizeBinaryArray::get_size(data_type)
+                * (array.offset as usize + array.length as usize)
         }
         (PhysicalType::FixedSizeList, 1) => {
             if let DataType::FixedSizeList(_, size) = data_type.to_logical_type() {
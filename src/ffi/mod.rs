@@ -11,14 +11,15 @@ pub(crate) use array::{ArrowArrayRef, InternalArrowArray};
 
 use std::sync::Arc;
 
-use crate::array::Array;
+use crate::array::{Array, StructArray};
+use crate::chunk::Chunk;
 use crate::datatypes::{DataType, Field};
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 
 use self::schema::to_field;
 
 pub use generated::{ArrowArray, ArrowArrayStream, ArrowSchema};
-pub use stream::{export_iterator, ArrowArrayStreamReader};
+pub use stream::{export_iterator, ArrowArrayStreamReader, ArrowArrayStreamWriter};
 
 /// Exports an [`Arc<dyn Array>`] to the C data interface.
 /// # Safety
@@ -40,7 +41,10 @@ pub unsafe fn export_field_to_c(field: &Field, ptr: *mut ArrowSchema) {
 /// # Safety
 /// This function is intrinsically `unsafe` and relies on a [`ArrowSchema`]
 /// valid according to the [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html) (FFI).
+/// # Errors
+/// This function errors when [`ArrowSchema::validate`] errors or the schema is not supported.
 pub unsafe fn import_field_from_c(field: &ArrowSchema) -> Result<Field> {
+    field.validate()?;
     to_field(field)
 }
 
@@ -54,3 +58,58 @@ pub unsafe fn import_array_from_c(
 ) -> Result<Box<dyn Array>> {
     try_from(Arc::new(InternalArrowArray::new(array, data_type)))
 }
+
+/// Exports a [`Chunk`] (a record batch) to the C data interface as a struct array
+/// whose children are the columns of the chunk, and its corresponding schema,
+/// a struct field (with a blank name) whose children are `fields`.
+/// # Panics
+/// This function panics if `fields` do not match the arrays of `chunk` or if `chunk` has no arrays.
+/// # Safety
+/// The pointers `array` and `schema` must be allocated and valid
+pub unsafe fn export_chunk_to_c(
+    chunk: Chunk<Arc<dyn Array>>,
+    fields: &[Field],
+    array: *mut ArrowArray,
+    schema: *mut ArrowSchema,
+) {
+    let data_type = DataType::Struct(fields.to_vec());
+    let field = Field::new("", data_type.clone(), false);
+    let struct_array = StructArray::from_data(data_type, chunk.into_arrays(), None);
+
+    export_array_to_c(Arc::new(struct_array), array);
+    export_field_to_c(&field, schema);
+}
+
+/// Imports a [`Chunk`] (a record batch) and its [`Field`]s from the C data interface,
+/// exported as a struct array whose children are the columns of the chunk (e.g. via [`export_chunk_to_c`]).
+/// The name of the struct field is ignored.
+/// # Errors
+/// This function errors if the schema is not a struct or if the struct array contains nulls.
+/// # Safety
+/// This function is intrinsically `unsafe` and relies on a [`ArrowArray`] and [`ArrowSchema`]
+/// valid according to the [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html) (FFI).
+pub unsafe fn import_chunk_from_c(
+    array: Box<ArrowArray>,
+    schema: &ArrowSchema,
+) -> Result<(Vec<Field>, Chunk<Arc<dyn Array>>)> {
+    let field = import_field_from_c(schema)?;
+    if !matches!(field.data_type(), DataType::Struct(_)) {
+        return Err(ArrowError::InvalidArgumentError(
+            "A chunk can only be imported from a struct array".to_string(),
+        ));
+    }
+
+    let array = import_array_from_c(array, field.data_type)?;
+    if array.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "A chunk can only be imported from a struct array without nulls".to_string(),
+        ));
+    }
+    let array = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .clone();
+    let (fields, arrays, _) = array.into_data();
+    Ok((fields, Chunk::try_new(arrays)?))
+}
@@ -46,7 +46,8 @@ impl ArrowSchema {
         let mut flags = field.is_nullable as i64 * 2;
 
         // allocate (and hold) the children
-        let children_vec = match field.data_type() {
+        // note: extension types are exported as their logical type plus metadata.
+        let children_vec = match field.data_type().to_logical_type() {
             DataType::List(field) => {
                 vec![Box::new(ArrowSchema::new(field.as_ref()))]
             }
@@ -77,7 +78,9 @@ impl ArrowSchema {
             .collect::<Box<_>>();
         let n_children = children_ptr.len() as i64;
 
-        let dictionary = if let DataType::Dictionary(_, values, is_ordered) = field.data_type() {
+        let dictionary = if let DataType::Dictionary(_, values, is_ordered) =
+            field.data_type().to_logical_type()
+        {
             flags += *is_ordered as i64;
             // we do not store field info in the dict values, so can't recover it all :(
             let field = Field::new("", values.as_ref().clone(), true);
@@ -153,6 +156,56 @@ impl ArrowSchema {
         }
     }
 
+    /// Validates this [`ArrowSchema`] and its children and dictionary against the
+    /// [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html), so that
+    /// importing it does not panic.
+    /// # Errors
+    /// This function errors with [`ArrowError::OutOfSpec`] iff:
+    /// * `format` is null or is not utf8
+    /// * `n_children` is negative, or is positive and `children` (or any child) is null
+    /// * `dictionary` is not null and `format` is not an integer
+    pub fn validate(&self) -> Result<()> {
+        if self.format.is_null() {
+            return Err(ArrowError::OutOfSpec(
+                "The format of an ArrowSchema must not be null".to_string(),
+            ));
+        }
+        // safe because the lifetime of `self.format` equals `self`
+        let format = unsafe { CStr::from_ptr(self.format) }
+            .to_str()
+            .map_err(|_| {
+                ArrowError::OutOfSpec("The format of an ArrowSchema must be utf8".to_string())
+            })?;
+
+        if self.n_children < 0 {
+            return Err(ArrowError::OutOfSpec(
+                "The number of children of an ArrowSchema must not be negative".to_string(),
+            ));
+        }
+        if self.n_children > 0 && self.children.is_null() {
+            return Err(ArrowError::OutOfSpec(
+                "An ArrowSchema with children must have non-null children".to_string(),
+            ));
+        }
+        for index in 0..self.n_children as usize {
+            // safe because `children` has `n_children` entries
+            let child = unsafe { *self.children.add(index) };
+            if child.is_null() {
+                return Err(ArrowError::OutOfSpec(format!(
+                    "The child {} of an ArrowSchema must not be null",
+                    index
+                )));
+            }
+            unsafe { &*child }.validate()?;
+        }
+
+        if let Some(dictionary) = self.dictionary() {
+            to_integer_type(format)?;
+            dictionary.validate()?;
+        }
+        Ok(())
+    }
+
     /// returns the format of this schema.
     pub(crate) fn format(&self) -> &str {
         assert!(!self.format.is_null());
@@ -267,6 +320,8 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
         "tDn" => DataType::Duration(TimeUnit::Nanosecond),
         "tiM" => DataType::Interval(IntervalUnit::YearMonth),
         "tiD" => DataType::Interval(IntervalUnit::DayTime),
+        // "tiN" was emitted by previous versions of this crate
+        "tin" | "tiN" => DataType::Interval(IntervalUnit::MonthDayNano),
         "+l" => {
             let child = schema.child(0);
             DataType::List(Box::new(to_field(child)?))
@@ -288,15 +343,18 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
             DataType::Struct(children)
         }
         other => {
-            let parts = other.split(':').collect::<Vec<_>>();
+            // the timezone of a timestamp may contain `:` (e.g. "tss:+01:00")
+            let parts = other.splitn(2, ':').collect::<Vec<_>>();
+            // an empty timezone (e.g. "tss:") denotes a timestamp without timezone
+            let timezone = || (!parts[1].is_empty()).then(|| parts[1].to_string());
             if parts.len() == 2 && parts[0] == "tss" {
-                DataType::Timestamp(TimeUnit::Second, Some(parts[1].to_string()))
+                DataType::Timestamp(TimeUnit::Second, timezone())
             } else if parts.len() == 2 && parts[0] == "tsm" {
-                DataType::Timestamp(TimeUnit::Millisecond, Some(parts[1].to_string()))
+                DataType::Timestamp(TimeUnit::Millisecond, timezone())
             } else if parts.len() == 2 && parts[0] == "tsu" {
-                DataType::Timestamp(TimeUnit::Microsecond, Some(parts[1].to_string()))
+                DataType::Timestamp(TimeUnit::Microsecond, timezone())
             } else if parts.len() == 2 && parts[0] == "tsn" {
-                DataType::Timestamp(TimeUnit::Nanosecond, Some(parts[1].to_string()))
+                DataType::Timestamp(TimeUnit::Nanosecond, timezone())
             } else if parts.len() == 2 && parts[0] == "w" {
                 let size = parts[1].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("size is not a valid integer".to_string())
@@ -315,25 +373,30 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
                         "Decimal must contain 2 or 3 comma-separated values".to_string(),
                     ));
                 };
-                if parts.len() == 3 {
-                    let bit_width = parts[0].parse::<usize>().map_err(|_| {
-                        ArrowError::OutOfSpec(
-                            "Decimal bit width is not a valid integer".to_string(),
-                        )
-                    })?;
-                    if bit_width != 128 {
-                        return Err(ArrowError::OutOfSpec(
-                            "Decimal256 is not supported".to_string(),
-                        ));
-                    }
-                }
                 let precision = parts[0].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("Decimal precision is not a valid integer".to_string())
                 })?;
                 let scale = parts[1].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("Decimal scale is not a valid integer".to_string())
                 })?;
-                DataType::Decimal(precision, scale)
+                let bit_width = if parts.len() == 3 {
+                    parts[2].parse::<usize>().map_err(|_| {
+                        ArrowError::OutOfSpec(
+                            "Decimal bit width is not a valid integer".to_string(),
+                        )
+                    })?
+                } else {
+                    128
+                };
+                match bit_width {
+                    128 => DataType::Decimal(precision, scale),
+                    256 => DataType::Decimal256(precision, scale),
+                    _ => {
+                        return Err(ArrowError::OutOfSpec(
+                            "Decimal bit width must be either 128 or 256".to_string(),
+                        ))
+                    }
+                }
             } else if !parts.is_empty() && ((parts[0] == "+us") || (parts[0] == "+ud")) {
                 // union
                 let mode = UnionMode::sparse(parts[0] == "+us");
@@ -399,9 +462,7 @@ fn to_format(data_type: &DataType) -> String {
         DataType::Duration(TimeUnit::Nanosecond) => "tDn".to_string(),
         DataType::Interval(IntervalUnit::YearMonth) => "tiM".to_string(),
         DataType::Interval(IntervalUnit::DayTime) => "tiD".to_string(),
-        DataType::Interval(IntervalUnit::MonthDayNano) => {
-            todo!("Spec for FFI for MonthDayNano still not defined.")
-        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => "tin".to_string(),
         DataType::Timestamp(unit, tz) => {
             let unit = match unit {
                 TimeUnit::Second => "s",
@@ -416,6 +477,7 @@ fn to_format(data_type: &DataType) -> String {
             )
         }
         DataType::Decimal(precision, scale) => format!("d:{},{}", precision, scale),
+        DataType::Decimal256(precision, scale) => format!("d:{},{},256", precision, scale),
         DataType::List(_) => "+l".to_string(),
         DataType::LargeList(_) => "+L".to_string(),
         DataType::Struct(_) => "+s".to_string(),
@@ -511,3 +573,156 @@ unsafe fn metadata_from_bytes(data: *const ::std::os::raw::c_char) -> (Metadata,
     let extension = extension_name.map(|name| (name, extension_metadata));
     (result, extension)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all() {
+        let mut dts = vec![
+            DataType::Null,
+            DataType::Boolean,
+            DataType::UInt8,
+            DataType::UInt16,
+            DataType::UInt32,
+            DataType::UInt64,
+            DataType::Int8,
+            DataType::Int16,
+            DataType::Int32,
+            DataType::Int64,
+            DataType::Float16,
+            DataType::Float32,
+            DataType::Float64,
+            DataType::Date32,
+            DataType::Date64,
+            DataType::Time32(TimeUnit::Second),
+            DataType::Time32(TimeUnit::Millisecond),
+            DataType::Time64(TimeUnit::Microsecond),
+            DataType::Time64(TimeUnit::Nanosecond),
+            DataType::Decimal(5, 5),
+            DataType::Decimal256(76, 2),
+            DataType::Utf8,
+            DataType::LargeUtf8,
+            DataType::Binary,
+            DataType::LargeBinary,
+            DataType::FixedSizeBinary(2),
+            DataType::List(Box::new(Field::new("example", DataType::Boolean, false))),
+            DataType::FixedSizeList(Box::new(Field::new("example", DataType::Boolean, false)), 2),
+            DataType::LargeList(Box::new(Field::new("example", DataType::Boolean, false))),
+            DataType::LargeList(Box::new(Field::new("example", DataType::Int32, true))),
+            DataType::Struct(vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new(
+                    "b",
+                    DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                    true,
+                ),
+            ]),
+            DataType::Map(
+                Box::new(Field::new(
+                    "entries",
+                    DataType::Struct(vec![
+                        Field::new("key", DataType::Utf8, false),
+                        Field::new("value", DataType::Int32, true),
+                    ]),
+                    false,
+                )),
+                false,
+            ),
+            DataType::Union(
+                vec![
+                    Field::new("a", DataType::Int64, true),
+                    Field::new("b", DataType::Utf8, true),
+                ],
+                Some(vec![1, 2]),
+                UnionMode::Dense,
+            ),
+            DataType::Union(
+                vec![Field::new("a", DataType::Int64, true)],
+                Some(vec![0]),
+                UnionMode::Sparse,
+            ),
+            DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+            DataType::Dictionary(IntegerType::UInt64, Box::new(DataType::Binary), true),
+            DataType::Extension(
+                "a".to_string(),
+                Box::new(DataType::Struct(vec![Field::new(
+                    "a",
+                    DataType::Int8,
+                    true,
+                )])),
+                Some("metadata".to_string()),
+            ),
+        ];
+        for time_unit in [
+            TimeUnit::Second,
+            TimeUnit::Millisecond,
+            TimeUnit::Microsecond,
+            TimeUnit::Nanosecond,
+        ] {
+            dts.push(DataType::Timestamp(time_unit, None));
+            dts.push(DataType::Timestamp(time_unit, Some("UTC".to_string())));
+            dts.push(DataType::Timestamp(time_unit, Some("+01:00".to_string())));
+            dts.push(DataType::Duration(time_unit));
+        }
+        for interval_type in [
+            IntervalUnit::DayTime,
+            IntervalUnit::YearMonth,
+            IntervalUnit::MonthDayNano,
+        ] {
+            dts.push(DataType::Interval(interval_type));
+        }
+
+        for expected in dts {
+            let field = Field::new("a", expected.clone(), true);
+            let schema = ArrowSchema::new(&field);
+            let result = unsafe { to_field(&schema).unwrap() };
+            assert_eq!(result.data_type(), &expected);
+        }
+    }
+
+    #[test]
+    fn metadata_encoding() {
+        let mut metadata = BTreeMap::new();
+        metadata.insert("a".to_string(), "bc".to_string());
+        metadata.insert("key".to_string(), "".to_string());
+
+        // As per the C data interface, the integers of the metadata are in native endianness,
+        // since it is only shared within a process.
+        let mut expected = 2i32.to_ne_bytes().to_vec();
+        expected.extend(1i32.to_ne_bytes());
+        expected.extend(b"a");
+        expected.extend(2i32.to_ne_bytes());
+        expected.extend(b"bc");
+        expected.extend(3i32.to_ne_bytes());
+        expected.extend(b"key");
+        expected.extend(0i32.to_ne_bytes());
+        assert_eq!(metadata_to_bytes(&metadata), expected);
+    }
+
+    #[test]
+    fn import_dictionary_non_integer_keys() {
+        let field = Field::new(
+            "a",
+            DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+            true,
+        );
+        let mut schema = ArrowSchema::new(&field);
+        assert!(schema.validate().is_ok());
+
+        // the original format is still released alongside the schema's private data
+        let format = CString::new("u").unwrap();
+        schema.format = format.as_ptr();
+        assert!(schema.validate().is_err());
+        assert!(unsafe { to_field(&schema) }.is_err());
+    }
+
+    #[test]
+    fn interval_month_day_nano_format() {
+        assert_eq!(
+            to_format(&DataType::Interval(IntervalUnit::MonthDayNano)),
+            "tin"
+        );
+    }
+}
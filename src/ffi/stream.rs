@@ -1,7 +1,12 @@
 use std::ffi::{CStr, CString};
 use std::sync::Arc;
 
-use crate::{array::Array, datatypes::Field, error::ArrowError};
+use crate::{
+    array::{Array, StructArray},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+};
 
 use super::{export_array_to_c, export_field_to_c, import_array_from_c, import_field_from_c};
 use super::{ArrowArray, ArrowArrayStream, ArrowSchema};
@@ -65,6 +70,8 @@ impl ArrowArrayStreamReader {
     /// In particular:
     /// * The `ArrowArrayStream` fulfills the invariants of the C stream interface
     /// * The schema `get_schema` produces fulfills the C data interface
+    /// * Every array `get_next` produces fulfills the C data interface (this is assumed when
+    ///   iterating over the returned reader)
     pub unsafe fn try_new(mut iter: Box<ArrowArrayStream>) -> Result<Self, ArrowError> {
         let mut field = Box::new(ArrowSchema::empty());
 
@@ -101,17 +108,20 @@ impl ArrowArrayStreamReader {
     pub fn field(&self) -> &Field {
         &self.field
     }
+}
+
+impl Iterator for ArrowArrayStreamReader {
+    type Item = Result<Box<dyn Array>, ArrowError>;
 
-    /// Advances this iterator by one array
+    /// Advances this iterator by one array, calling the stream's `get_next`.
     /// # Error
     /// Errors iff:
     /// * The C stream interface returns an error
-    /// * The C stream interface returns an invalid array (that we can identify, see Safety below)
-    /// # Safety
-    /// Calling this iterator's `next` assumes that the [`ArrowArrayStream`] produces arrow arrays
-    /// that fulfill the C data interface
-    pub unsafe fn next(&mut self) -> Option<Result<Box<dyn Array>, ArrowError>> {
+    /// * The C stream interface returns an invalid array (that we can identify)
+    fn next(&mut self) -> Option<Self::Item> {
         let mut array = Box::new(ArrowArray::empty());
+        // Safety: the invariants of the C stream interface were assumed by the (`unsafe`)
+        // construction of this reader via [`ArrowArrayStreamReader::try_new`]
         let status = unsafe { (self.iter.get_next.unwrap())(&mut *self.iter, &mut *array) };
 
         if status != 0 {
@@ -121,7 +131,7 @@ impl ArrowArrayStreamReader {
         // last paragraph of https://arrow.apache.org/docs/format/CStreamInterface.html#c.ArrowArrayStream.get_next
         array.release?;
 
-        // Safety: assumed from the C stream interface
+        // Safety: assumed by `try_new`
         unsafe { import_array_from_c(array, self.field.data_type.clone()) }
             .map(Some)
             .transpose()
@@ -221,3 +231,78 @@ pub unsafe fn export_iterator(
         private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
     }
 }
+
+/// Exports an iterator of [`Chunk`]s of a given [`Schema`] to the
+/// [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+///
+/// Like other implementations of the interface (e.g. pyarrow's `RecordBatchReader`), the stream
+/// exports each batch as a [`StructArray`] whose fields are the schema's fields.
+/// Batches are pulled from the iterator lazily, one per call of the consumer's `get_next`,
+/// so that they are never all held in memory.
+pub struct ArrowArrayStreamWriter {
+    schema: Schema,
+    iter: Box<dyn Iterator<Item = Result<Chunk<Arc<dyn Array>>, ArrowError>>>,
+}
+
+impl ArrowArrayStreamWriter {
+    /// Returns a new [`ArrowArrayStreamWriter`] of the batches of `iter`, of `schema`.
+    pub fn new(
+        schema: Schema,
+        iter: Box<dyn Iterator<Item = Result<Chunk<Arc<dyn Array>>, ArrowError>>>,
+    ) -> Self {
+        Self { schema, iter }
+    }
+
+    /// Returns the schema of this writer
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Exports the batches to the C stream interface.
+    ///
+    /// A batch whose number of columns or [`DataType`]s differ from the schema's is reported
+    /// to the consumer as an error.
+    /// # Safety
+    /// The pointer `consumer` must be allocated
+    pub unsafe fn export(self, consumer: *mut ArrowArrayStream) {
+        let fields = self.schema.fields;
+        let data_type = DataType::Struct(fields.clone());
+        let field = Field::new("", data_type.clone(), false).with_metadata(self.schema.metadata);
+        let iter = self.iter.map(move |chunk| {
+            let chunk = chunk?;
+            check_chunk(&fields, &chunk)?;
+            let array: Arc<dyn Array> = Arc::new(StructArray::new(
+                data_type.clone(),
+                chunk.into_arrays(),
+                None,
+            ));
+            Ok::<_, ArrowError>(array)
+        });
+        export_iterator(Box::new(iter), field, consumer)
+    }
+}
+
+/// Errors iff the number of columns of `chunk` or their [`DataType`]s differ from `fields`.
+fn check_chunk(fields: &[Field], chunk: &Chunk<Arc<dyn Array>>) -> Result<(), ArrowError> {
+    if chunk.arrays().len() != fields.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The batch has {} columns but the stream expects {}",
+            chunk.arrays().len(),
+            fields.len()
+        )));
+    }
+    if let Some((array, field)) = chunk
+        .arrays()
+        .iter()
+        .zip(fields.iter())
+        .find(|(array, field)| array.data_type() != field.data_type())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The column \"{}\" has data type {:?} but the stream expects data type {:?}",
+            field.name,
+            array.data_type(),
+            field.data_type()
+        )));
+    }
+    Ok(())
+}
@@ -197,8 +197,13 @@ fn get_data_type(
             (DataType::Duration(time_unit), IpcField::default())
         }
         Decimal(decimal) => {
-            let data_type =
-                DataType::Decimal(decimal.precision()? as usize, decimal.scale()? as usize);
+            let precision = decimal.precision()? as usize;
+            let scale = decimal.scale()? as usize;
+            let data_type = match decimal.bit_width()? {
+                128 => DataType::Decimal(precision, scale),
+                256 => DataType::Decimal256(precision, scale),
+                _ => return Err(ArrowError::oos("IPC: decimal bit width must be 128 or 256")),
+            };
             (data_type, IpcField::default())
         }
         List(_) => {
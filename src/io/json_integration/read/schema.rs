@@ -174,20 +174,38 @@ fn to_data_type(item: &Value, mut children: Vec<Field>) -> Result<DataType> {
         "largeutf8" => LargeUtf8,
         "decimal" => {
             // return a list with any type as its child isn't defined in the map
-            let precision = match item.get("precision") {
-                Some(p) => Ok(p.as_u64().unwrap() as usize),
-                None => Err(ArrowError::OutOfSpec(
+            let precision = match item.get("precision").map(|p| p.as_u64()) {
+                Some(Some(p)) => Ok(p as usize),
+                _ => Err(ArrowError::OutOfSpec(
                     "Expecting a precision for decimal".to_string(),
                 )),
             };
-            let scale = match item.get("scale") {
-                Some(s) => Ok(s.as_u64().unwrap() as usize),
+            let scale = match item.get("scale").map(|s| s.as_u64()) {
+                Some(Some(s)) => Ok(s as usize),
                 _ => Err(ArrowError::OutOfSpec(
                     "Expecting a scale for decimal".to_string(),
                 )),
             };
 
-            DataType::Decimal(precision?, scale?)
+            let bit_width = match item.get("bitWidth").map(|b| b.as_u64()) {
+                Some(Some(b)) => b,
+                Some(None) => {
+                    return Err(ArrowError::OutOfSpec(
+                        "Decimal bit width must be an unsigned integer".to_string(),
+                    ))
+                }
+                None => 128,
+            };
+
+            match bit_width {
+                128 => DataType::Decimal(precision?, scale?),
+                256 => DataType::Decimal256(precision?, scale?),
+                _ => {
+                    return Err(ArrowError::OutOfSpec(
+                        "Decimal bit width must be either 128 or 256".to_string(),
+                    ))
+                }
+            }
         }
         "floatingpoint" => match item.get("precision") {
             Some(p) if p == "HALF" => DataType::Float16,
@@ -0,0 +1,51 @@
+//! Adapters of asynchronous streams of [`DataPage`]s into [`DataPages`](super::DataPages).
+use std::collections::VecDeque;
+
+use futures::{Stream, TryStreamExt};
+use parquet2::{error::Error as ParquetError, page::DataPage, FallibleStreamingIterator};
+
+/// Trait describing a [`Stream`] of [`DataPage`], the asynchronous counterpart of
+/// [`DataPages`](super::DataPages).
+pub trait AsyncDataPages: Stream<Item = Result<DataPage, ParquetError>> + Send {}
+
+impl<S: Stream<Item = Result<DataPage, ParquetError>> + Send> AsyncDataPages for S {}
+
+/// [`DataPages`](super::DataPages) whose pages were buffered from an [`AsyncDataPages`], so that they can be
+/// deserialized by the (synchronous) decoders of this crate.
+/// # Implementation
+/// All pages of the stream are buffered in memory; this is bounded by the size of a column chunk.
+pub struct BufferedDataPages {
+    pages: VecDeque<DataPage>,
+    current: Option<DataPage>,
+}
+
+impl BufferedDataPages {
+    /// Returns a new [`BufferedDataPages`] by consuming all pages from `pages`.
+    /// # Errors
+    /// This function errors iff any of the pages of `pages` is an error.
+    pub async fn try_new<S: AsyncDataPages>(pages: S) -> Result<Self, ParquetError> {
+        let pages = pages.try_collect::<VecDeque<_>>().await?;
+        Ok(Self {
+            pages,
+            current: None,
+        })
+    }
+}
+
+impl FallibleStreamingIterator for BufferedDataPages {
+    type Item = DataPage;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> Result<(), ParquetError> {
+        self.current = self.pages.pop_front();
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&DataPage> {
+        self.current.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pages.len(), Some(self.pages.len()))
+    }
+}
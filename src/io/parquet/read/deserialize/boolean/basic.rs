@@ -102,8 +102,27 @@ impl<'a> DecodedState<'a> for (MutableBitmap, MutableBitmap) {
     }
 }
 
+/// Options to decode boolean parquet pages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DecodeOptions {
+    /// When set, every null slot is filled with this value and marked as valid.
+    pub null_as: Option<bool>,
+}
+
+/// Replaces every null slot from `start` by `value`, marking it as valid.
+fn fill_nulls(values: &mut MutableBitmap, validity: &mut MutableBitmap, start: usize, value: bool) {
+    for index in start..validity.len() {
+        if !validity.get(index) {
+            values.set(index, value);
+            validity.set(index, true);
+        }
+    }
+}
+
 #[derive(Default)]
-struct BooleanDecoder {}
+struct BooleanDecoder {
+    options: DecodeOptions,
+}
 
 impl<'a> Decoder<'a> for BooleanDecoder {
     type State = State<'a>;
@@ -145,6 +164,7 @@ impl<'a> Decoder<'a> for BooleanDecoder {
         remaining: usize,
     ) {
         let (values, validity) = decoded;
+        let start = validity.len();
         match state {
             State::Optional(page_validity, page_values) => extend_from_decoder(
                 validity,
@@ -174,6 +194,9 @@ impl<'a> Decoder<'a> for BooleanDecoder {
                 );
             }
         }
+        if let Some(value) = self.options.null_as {
+            fill_nulls(values, validity, start, value)
+        }
     }
 }
 
@@ -188,15 +211,29 @@ pub struct Iter<I: DataPages> {
     data_type: DataType,
     items: VecDeque<(MutableBitmap, MutableBitmap)>,
     chunk_size: usize,
+    options: DecodeOptions,
 }
 
 impl<I: DataPages> Iter<I> {
+    /// Returns a new [`Iter`] that decodes `iter` into [`BooleanArray`]s of `chunk_size`.
     pub fn new(iter: I, data_type: DataType, chunk_size: usize) -> Self {
+        Self::new_with_options(iter, data_type, chunk_size, DecodeOptions::default())
+    }
+
+    /// Returns a new [`Iter`] that decodes `iter` into [`BooleanArray`]s of `chunk_size`
+    /// according to `options`.
+    pub fn new_with_options(
+        iter: I,
+        data_type: DataType,
+        chunk_size: usize,
+        options: DecodeOptions,
+    ) -> Self {
         Self {
             iter,
             data_type,
             items: VecDeque::new(),
             chunk_size,
+            options,
         }
     }
 }
@@ -209,7 +246,9 @@ impl<I: DataPages> Iterator for Iter<I> {
             &mut self.iter,
             &mut self.items,
             self.chunk_size,
-            &BooleanDecoder::default(),
+            &BooleanDecoder {
+                options: self.options,
+            },
         );
         match maybe_state {
             MaybeNext::Some(Ok((values, validity))) => {
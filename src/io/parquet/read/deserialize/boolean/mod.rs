@@ -11,7 +11,7 @@ use super::{
     DataPages,
 };
 
-pub use self::basic::Iter;
+pub use self::basic::{DecodeOptions, Iter};
 
 /// Converts [`DataPages`] to an [`Iterator`] of [`Array`]
 pub fn iter_to_arrays_nested<'a, I: 'a>(
@@ -20,6 +20,8 @@ use self::nested_utils::{InitNested, NestedArrayIter, NestedState};
 use parquet2::schema::types::PrimitiveType;
 use simple::page_iter_to_arrays;
 
+pub use boolean::{DecodeOptions, Iter as BooleanIter};
+
 use super::*;
 
 /// Creates a new iterator of compressed pages.
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
+use crate::compute::aggregate::estimated_bytes_size;
 use crate::datatypes::Schema;
 use crate::io::parquet::read::read_columns_many;
 use crate::{
@@ -10,6 +11,7 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+use super::row_group::get_field_columns;
 use super::{infer_schema, read_metadata, FileMetaData, RowGroupDeserializer, RowGroupMetaData};
 
 type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool + Send + Sync>;
@@ -19,6 +21,8 @@ type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool + Send + Sync>;
 /// This can be thought of a flatten chain of [`Iterator<Item=Chunk>`] - each row group is sequentially
 /// mapped to an [`Iterator<Item=Chunk>`] and each iterator is iterated upon until either the limit
 /// or the last iterator ends.
+///
+/// The memory of the yielded chunks can be bounded via [`FileReader::set_max_bytes`].
 /// # Implementation
 /// This iterator mixes IO-bounded and CPU-bounded operations.
 pub struct FileReader<R: Read + Seek> {
@@ -26,6 +30,8 @@ pub struct FileReader<R: Read + Seek> {
     metadata: FileMetaData,
     remaining_rows: usize,
     current_row_group: Option<RowGroupDeserializer>,
+    max_bytes: Option<usize>,
+    yielded_bytes: usize,
 }
 
 impl<R: Read + Seek> FileReader<R> {
@@ -94,6 +100,8 @@ impl<R: Read + Seek> FileReader<R> {
             metadata,
             remaining_rows: limit.unwrap_or(usize::MAX),
             current_row_group: None,
+            max_bytes: None,
+            yielded_bytes: 0,
         })
     }
 
@@ -112,7 +120,73 @@ impl<R: Read + Seek> FileReader<R> {
         self.row_groups.set_groups_filter(groups_filter);
     }
 
+    /// Sets the maximum number of bytes that this reader yields, as estimated by
+    /// [`estimated_bytes_size`] over the arrays of each [`Chunk`].
+    /// Before reading a row group, the uncompressed size of its columns (from its metadata) is
+    /// checked against the remaining budget, so that its column chunks are neither read nor
+    /// decoded when they would exceed it.
+    /// Once either exceeds this budget, the reader yields an error and stops.
+    pub fn set_max_bytes(&mut self, max_bytes: usize) {
+        self.max_bytes = Some(max_bytes);
+    }
+
+    /// Returns the number of bytes yielded so far, as estimated by [`estimated_bytes_size`].
+    pub fn yielded_bytes(&self) -> usize {
+        self.yielded_bytes
+    }
+
+    fn exceeded_budget(&mut self, bytes: usize, max_bytes: usize) -> ArrowError {
+        // stop reading
+        self.remaining_rows = 0;
+        self.current_row_group = None;
+        ArrowError::InvalidArgumentError(format!(
+            "Reading parquet exceeded the maximum number of bytes ({} > {})",
+            bytes, max_bytes
+        ))
+    }
+
+    fn check_budget(&mut self, chunk: Chunk<Arc<dyn Array>>) -> Result<Chunk<Arc<dyn Array>>> {
+        self.yielded_bytes += chunk
+            .arrays()
+            .iter()
+            .map(|array| estimated_bytes_size(array.as_ref()))
+            .sum::<usize>();
+        match self.max_bytes {
+            Some(max_bytes) if self.yielded_bytes > max_bytes => {
+                Err(self.exceeded_budget(self.yielded_bytes, max_bytes))
+            }
+            _ => Ok(chunk),
+        }
+    }
+
+    /// Checks the uncompressed size of the (projected) columns of the next row group against
+    /// the remaining budget, so that it is neither read nor decoded when it would exceed it.
+    fn check_row_group_budget(&mut self) -> Result<()> {
+        let max_bytes = match self.max_bytes {
+            Some(max_bytes) => max_bytes,
+            None => return Ok(()),
+        };
+        let size = match self.row_groups.peek() {
+            Some(row_group) => self
+                .row_groups
+                .schema
+                .fields
+                .iter()
+                .flat_map(|field| get_field_columns(row_group.columns(), &field.name))
+                .map(|column| column.uncompressed_size() as usize)
+                .sum::<usize>(),
+            None => return Ok(()),
+        };
+        let bytes = self.yielded_bytes + size;
+        if bytes > max_bytes {
+            Err(self.exceeded_budget(bytes, max_bytes))
+        } else {
+            Ok(())
+        }
+    }
+
     fn next_row_group(&mut self) -> Result<Option<RowGroupDeserializer>> {
+        self.check_row_group_budget()?;
         let result = self.row_groups.next().transpose()?;
 
         self.remaining_rows = self.remaining_rows.saturating_sub(
@@ -123,12 +197,8 @@ impl<R: Read + Seek> FileReader<R> {
         );
         Ok(result)
     }
-}
-
-impl<R: Read + Seek> Iterator for FileReader<R> {
-    type Item = Result<Chunk<Arc<dyn Array>>>;
 
-    fn next(&mut self) -> Option<Self::Item> {
+    fn next_chunk(&mut self) -> Option<Result<Chunk<Arc<dyn Array>>>> {
         if self.remaining_rows == 0 {
             // reached the limit
             return None;
@@ -141,7 +211,7 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
                     Ok(Some(row_group)) => {
                         self.current_row_group = Some(row_group);
                         // new found => pull again
-                        self.next()
+                        self.next_chunk()
                     }
                     Ok(None) => {
                         self.current_row_group = None;
@@ -155,7 +225,7 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
             match self.next_row_group() {
                 Ok(Some(row_group)) => {
                     self.current_row_group = Some(row_group);
-                    self.next()
+                    self.next_chunk()
                 }
                 Ok(None) => {
                     self.current_row_group = None;
@@ -167,6 +237,15 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     }
 }
 
+impl<R: Read + Seek> Iterator for FileReader<R> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.next_chunk()?;
+        Some(chunk.and_then(|chunk| self.check_budget(chunk)))
+    }
+}
+
 /// An [`Iterator<Item=RowGroupDeserializer>`] from row groups of a parquet file.
 ///
 /// # Implementation
@@ -208,6 +287,24 @@ impl<R: Read + Seek> RowGroupReader<R> {
         self.groups_filter = Some(groups_filter);
     }
 
+    /// Returns the metadata of the row group that the next iteration reads, if any.
+    fn peek(&self) -> Option<&RowGroupMetaData> {
+        if self.schema.fields.is_empty() || self.remaining_rows == 0 {
+            return None;
+        }
+        self.row_groups
+            .iter()
+            .enumerate()
+            .skip(self.current_group)
+            .find(|(index, row_group)| {
+                self.groups_filter
+                    .as_ref()
+                    .map(|groups_filter| (groups_filter)(*index, row_group))
+                    .unwrap_or(true)
+            })
+            .map(|(_, row_group)| row_group)
+    }
+
     #[inline]
     fn _next(&mut self) -> Result<Option<RowGroupDeserializer>> {
         if self.schema.fields.is_empty() {
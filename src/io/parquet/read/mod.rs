@@ -1,6 +1,7 @@
 //! APIs to read from Parquet format.
 #![allow(clippy::type_complexity)]
 
+mod async_pages;
 mod deserialize;
 mod file;
 mod indexes;
@@ -36,9 +37,10 @@ pub use parquet2::{
     FallibleStreamingIterator,
 };
 
-use crate::{array::Array, error::Result};
+use crate::{array::Array, datatypes::Field, error::Result};
 
-pub use deserialize::{column_iter_to_arrays, get_page_iterator};
+pub use async_pages::{AsyncDataPages, BufferedDataPages};
+pub use deserialize::{column_iter_to_arrays, get_page_iterator, BooleanIter, DecodeOptions};
 pub use file::{FileReader, RowGroupReader};
 pub use indexes::{read_columns_indexes, ColumnIndex};
 pub use row_group::*;
@@ -55,6 +57,30 @@ impl<I: FallibleStreamingIterator<Item = DataPage, Error = ParquetError> + Send
 {
 }
 
+/// Returns the indices of the `row_groups` that may contain rows of `field` fulfilling
+/// `predicate`, i.e. it removes every row group whose [`statistics::ScalarStatistics`]
+/// make `predicate` return `false`.
+///
+/// `predicate` must return `true` whenever the statistics can't exclude the row group
+/// (e.g. when `min` or `max` are null because statistics were not written).
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn prune_row_groups(
+    row_groups: &[RowGroupMetaData],
+    field: &Field,
+    predicate: &dyn Fn(&statistics::ScalarStatistics) -> bool,
+) -> Result<Vec<usize>> {
+    row_groups
+        .iter()
+        .enumerate()
+        .filter_map(|(index, row_group)| {
+            statistics::deserialize_scalars(field, row_group)
+                .map(|statistics| predicate(&statistics).then(|| index))
+                .transpose()
+        })
+        .collect()
+}
+
 /// Type def for a sharable, boxed dyn [`Iterator`] of arrays
 pub type ArrayIter<'a> = Box<dyn Iterator<Item = Result<Arc<dyn Array>>> + Send + Sync + 'a>;
 
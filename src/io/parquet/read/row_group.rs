@@ -1,5 +1,6 @@
 use std::{
     io::{Read, Seek},
+    ops::Range,
     sync::Arc,
 };
 
@@ -8,12 +9,18 @@ use futures::{
     AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt,
 };
 use parquet2::{
+    indexes::{select_pages, Interval},
     metadata::ColumnChunkMetaData,
-    read::{BasicDecompressor, PageReader},
+    read::{read_pages_locations, BasicDecompressor, IndexedPageReader, PageReader},
 };
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
+    array::{Array, BooleanArray},
+    bitmap::Bitmap,
+    chunk::Chunk,
+    compute::filter::filter,
+    datatypes::{DataType, Field},
+    error::{ArrowError, Result},
     io::parquet::read::column_iter_to_arrays,
 };
 
@@ -197,6 +204,162 @@ pub fn to_deserializer<'a>(
     column_iter_to_arrays(columns, types, field, chunk_size)
 }
 
+/// Converts `ranges` of rows of a row group with `num_rows` rows into [`Interval`]s.
+/// # Errors
+/// Errors iff the ranges are not sorted, overlap, or are out of bounds.
+fn ranges_to_intervals(ranges: &[Range<usize>], num_rows: usize) -> Result<Vec<Interval>> {
+    let mut previous_end = 0;
+    ranges
+        .iter()
+        .map(|range| {
+            if range.start < previous_end || range.start > range.end || range.end > num_rows {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The row ranges must be sorted, non-overlapping and within the row group's {} rows, but got {:?}",
+                    num_rows, ranges
+                )));
+            }
+            previous_end = range.end;
+            Ok(Interval::new(range.start, range.end - range.start))
+        })
+        .collect()
+}
+
+/// Returns an [`ArrayIter`] of the parquet field whose name is [`Field`] that only contains
+/// the rows in `ranges` of `row_group`.
+///
+/// `ranges` are relative to the row group and must be sorted and non-overlapping.
+/// # Implementation
+/// This function uses the offset index (page locations) of the columns to only read the pages
+/// that contain rows in `ranges`; within those pages, only the requested rows are deserialized.
+/// It is IO-bounded `O(P)` where P is the number of selected pages.
+/// # Errors
+/// This function errors iff:
+/// * the row group does not contain the offset index
+/// * the ranges are not sorted, overlap, or are out of bounds
+/// * the pages can't be read
+pub fn read_columns_with_row_ranges<'a, R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    field: Field,
+    ranges: &[Range<usize>],
+    chunk_size: Option<usize>,
+) -> Result<ArrayIter<'a>> {
+    let num_rows = row_group.num_rows() as usize;
+    let intervals = ranges_to_intervals(ranges, num_rows)?;
+    let selected_rows = intervals.iter().map(|x| x.length).sum::<usize>();
+    if selected_rows == 0 {
+        return Ok(Box::new(std::iter::empty()));
+    }
+
+    // the page locations of every column in the row group
+    let locations = read_pages_locations(reader, row_group.columns())?;
+
+    let (columns, types): (Vec<_>, Vec<_>) = row_group
+        .columns()
+        .iter()
+        .zip(locations.into_iter())
+        .filter(|(column_meta, _)| column_meta.descriptor().path_in_schema[0] == field.name)
+        .map(|(column_meta, locations)| {
+            let pages = select_pages(&intervals, &locations, row_group.num_rows())?;
+            let pages = IndexedPageReader::new(&mut *reader, column_meta, pages, vec![], vec![])
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok((
+                BasicDecompressor::new(pages.into_iter().map(Ok), vec![]),
+                &column_meta.descriptor().descriptor.primitive_type,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    let chunk_size = chunk_size.unwrap_or(usize::MAX).min(selected_rows);
+    column_iter_to_arrays(columns, types, field, chunk_size)
+}
+
+/// Returns the ranges of consecutive set bits of `mask`.
+fn mask_to_ranges(mask: &Bitmap) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut start = None;
+    for (i, is_set) in mask.iter().enumerate() {
+        match (is_set, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                ranges.push(s..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        ranges.push(s..mask.len());
+    }
+    ranges
+}
+
+/// Returns an [`ArrayIter`] of the parquet field whose name is [`Field`] that only contains
+/// the rows of `row_group` whose bit in `mask` is set.
+///
+/// This is useful to push down a predicate evaluated on other columns: only the selected rows
+/// are deserialized, which is equivalent to, but cheaper than, deserializing all rows
+/// and filtering them with `mask`.
+/// # Implementation
+/// This function converts `mask` into ranges of rows and uses [`read_columns_with_row_ranges`].
+/// When the columns have no offset index (page locations), it falls back to reading
+/// and deserializing all rows and filtering each chunk with `mask`, in which case
+/// the chunks may have fewer than `chunk_size` rows.
+/// # Errors
+/// This function errors iff:
+/// * the length of `mask` differs from the number of rows of the row group
+/// * [`read_columns_with_row_ranges`] errors
+pub fn read_columns_with_row_mask<'a, R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    field: Field,
+    mask: &Bitmap,
+    chunk_size: Option<usize>,
+) -> Result<ArrayIter<'a>> {
+    if mask.len() != row_group.num_rows() as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The row mask has {} rows but the row group has {} rows",
+            mask.len(),
+            row_group.num_rows()
+        )));
+    }
+    let has_offset_index = row_group
+        .columns()
+        .iter()
+        .filter(|column_meta| column_meta.descriptor().path_in_schema[0] == field.name)
+        .all(|column_meta| column_meta.column_chunk().offset_index_offset.is_some());
+    if !has_offset_index {
+        return read_columns_and_filter(reader, row_group, field, mask, chunk_size);
+    }
+
+    let ranges = mask_to_ranges(mask);
+    read_columns_with_row_ranges(reader, row_group, field, &ranges, chunk_size)
+}
+
+/// Deserializes all rows of the parquet field whose name is [`Field`] and filters them by `mask`.
+fn read_columns_and_filter<'a, R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    field: Field,
+    mask: &Bitmap,
+    chunk_size: Option<usize>,
+) -> Result<ArrayIter<'a>> {
+    let columns = read_columns(reader, row_group.columns(), &field.name)?;
+    let arrays = to_deserializer(columns, field, row_group.num_rows() as usize, chunk_size)?;
+
+    let mask = mask.clone();
+    let mut offset = 0;
+    Ok(Box::new(arrays.map(move |array| {
+        let array = array?;
+        let chunk_mask = mask.clone().slice(offset, array.len());
+        offset += array.len();
+        let chunk_mask = BooleanArray::new(DataType::Boolean, chunk_mask, None);
+        filter(array.as_ref(), &chunk_mask).map(|array| array.into())
+    })))
+}
+
 /// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
 /// level parquet fields whose name matches `fields`'s names.
 ///
@@ -264,3 +427,50 @@ pub async fn read_columns_many_async<
         })
         .collect()
 }
+
+/// Reads all columns of `row_group` associated to `fields` and deserializes them into [`Chunk`]s
+/// of at most `chunk_size` rows, deserializing each field on a separate thread.
+///
+/// The columns of each [`Chunk`] are in the same order as `fields`.
+///
+/// # Implementation
+/// Reading the columns to memory is IO-bounded and single-threaded, like [`read_columns_many`].
+/// Deserializing them is CPU-bounded and is parallelized over the fields via `rayon`.
+#[cfg(feature = "parallel")]
+pub fn read_columns_parallel<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    use rayon::prelude::*;
+
+    let field_columns = fields
+        .iter()
+        .map(|field| read_columns(reader, row_group.columns(), &field.name))
+        .collect::<Result<Vec<_>>>()?;
+
+    let num_rows = row_group.num_rows() as usize;
+    // `collect` preserves the order of the fields
+    let columns = field_columns
+        .into_par_iter()
+        .zip(fields.into_par_iter())
+        .map(|(columns, field)| {
+            to_deserializer(columns, field, num_rows, chunk_size)?.collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let num_chunks = columns.first().map(|arrays| arrays.len()).unwrap_or(0);
+    if columns.iter().any(|arrays| arrays.len() != num_chunks) {
+        return Err(ArrowError::oos(
+            "All fields of a row group must deserialize to the same number of arrays",
+        ));
+    }
+    let mut columns = columns
+        .into_iter()
+        .map(|arrays| arrays.into_iter())
+        .collect::<Vec<_>>();
+    (0..num_chunks)
+        .map(|_| Chunk::try_new(columns.iter_mut().map(|x| x.next().unwrap()).collect()))
+        .collect()
+}
@@ -16,6 +16,7 @@ use crate::datatypes::IntervalUnit;
 use crate::datatypes::{DataType, Field, PhysicalType};
 use crate::error::ArrowError;
 use crate::error::Result;
+use crate::scalar::{new_scalar, Scalar};
 
 mod binary;
 mod boolean;
@@ -52,6 +53,17 @@ pub struct Statistics {
     pub max_value: Box<dyn Array>,
 }
 
+/// Arrow-deserialized parquet Statistics of a single column chunk, as [`Scalar`]s.
+#[derive(Debug, PartialEq)]
+pub struct ScalarStatistics {
+    /// number of nulls (a [`DataType::UInt64`] scalar or a struct thereof)
+    pub null_count: Box<dyn Scalar>,
+    /// Minimum
+    pub min: Box<dyn Scalar>,
+    /// Maximum
+    pub max: Box<dyn Scalar>,
+}
+
 /// Arrow-deserialized parquet Statistics of a file
 #[derive(Debug)]
 struct MutableStatistics {
@@ -425,3 +437,26 @@ pub fn deserialize(field: &Field, row_groups: &[RowGroupMetaData]) -> Result<Sta
 
     Ok(statistics.into())
 }
+
+/// Deserializes the statistics in the column chunks of `row_group` associated to `field`
+/// into [`ScalarStatistics`]. Missing statistics are represented by null scalars.
+///
+/// This is useful to evaluate predicates against a single row group (e.g. to skip it).
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_scalars(
+    field: &Field,
+    row_group: &RowGroupMetaData,
+) -> Result<ScalarStatistics> {
+    let statistics = deserialize(field, std::slice::from_ref(row_group))?;
+
+    let null_count = match &statistics.null_count {
+        Count::Single(array) => new_scalar(array, 0),
+        Count::Struct(array) => new_scalar(array, 0),
+    };
+    Ok(ScalarStatistics {
+        null_count,
+        min: new_scalar(statistics.min_value.as_ref(), 0),
+        max: new_scalar(statistics.max_value.as_ref(), 0),
+    })
+}
@@ -1,9 +1,11 @@
+use std::hash::{Hash, Hasher};
+
 use crate::{array::*, datatypes::DataType};
 
-use super::Scalar;
+use super::{ord::partial_cmp_nulls_last, Scalar, NULL_HASH_SENTINEL};
 
 /// The [`Scalar`] implementation of binary ([`Option<Vec<u8>>`]).
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BinaryScalar<O: Offset> {
     value: Option<Vec<u8>>,
     phantom: std::marker::PhantomData<O>,
@@ -52,4 +54,42 @@ impl<O: Offset> Scalar for BinaryScalar<O> {
             &DataType::Binary
         }
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        match &self.value {
+            Some(value) => Box::new(BinaryArray::<O>::from_trusted_len_values_iter(
+                std::iter::repeat(value).take(length),
+            )),
+            None => Box::new(BinaryArray::<O>::new_null(self.data_type().clone(), length)),
+        }
+    }
+}
+
+impl<O: Offset> Hash for BinaryScalar<O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.value() {
+            Some(value) => value.hash(state),
+            None => NULL_HASH_SENTINEL.hash(state),
+        }
+    }
+}
+
+impl<O: Offset> PartialOrd for BinaryScalar<O> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        partial_cmp_nulls_last(self.value(), other.value())
+    }
+}
+
+impl<O: Offset> std::fmt::Display for BinaryScalar<O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value() {
+            Some(value) => write!(f, "{:?}", value),
+            None => f.write_str("null"),
+        }
+    }
 }
@@ -1,9 +1,15 @@
-use crate::datatypes::DataType;
+use std::hash::{Hash, Hasher};
 
-use super::Scalar;
+use crate::{
+    array::{Array, BooleanArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+};
+
+use super::{ord::partial_cmp_nulls_last, Scalar, NULL_HASH_SENTINEL};
 
 /// The [`Scalar`] implementation of a boolean.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BooleanScalar {
     value: Option<bool>,
 }
@@ -37,6 +43,21 @@ impl Scalar for BooleanScalar {
     fn data_type(&self) -> &DataType {
         &DataType::Boolean
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        match self.value {
+            Some(value) => {
+                let mut values = MutableBitmap::with_capacity(length);
+                values.extend_constant(length, value);
+                Box::new(BooleanArray::new(DataType::Boolean, values.into(), None))
+            }
+            None => Box::new(BooleanArray::new_null(DataType::Boolean, length)),
+        }
+    }
 }
 
 impl From<Option<bool>> for BooleanScalar {
@@ -45,3 +66,28 @@ impl From<Option<bool>> for BooleanScalar {
         Self::new(v)
     }
 }
+
+impl Hash for BooleanScalar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.value {
+            Some(value) => value.hash(state),
+            None => NULL_HASH_SENTINEL.hash(state),
+        }
+    }
+}
+
+impl PartialOrd for BooleanScalar {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        partial_cmp_nulls_last(self.value, other.value)
+    }
+}
+
+impl std::fmt::Display for BooleanScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            Some(value) => write!(f, "{}", value),
+            None => f.write_str("null"),
+        }
+    }
+}
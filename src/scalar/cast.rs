@@ -0,0 +1,35 @@
+use crate::compute::cast::{can_cast_types, cast, CastOptions};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+use super::{new_scalar, Scalar};
+
+/// Casts a [`Scalar`] to `to_type`, returning a new [`Scalar`].
+///
+/// This reuses the array [`cast`] kernel: the scalar is converted to an array of length 1,
+/// casted, and its only element returned. Values that cannot be represented in `to_type`
+/// (e.g. overflows) become null, like in [`cast`] with the default [`CastOptions`].
+/// # Errors
+/// Errors with [`ArrowError::NotYetImplemented`] if the cast between the two types
+/// is not supported.
+/// # Example
+/// ```
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::{cast_scalar, PrimitiveScalar, Utf8Scalar};
+///
+/// let scalar = PrimitiveScalar::from(Some(1i32));
+/// let casted = cast_scalar(&scalar, &DataType::Utf8).unwrap();
+/// assert_eq!(casted.as_ref(), &Utf8Scalar::<i32>::from(Some("1")) as _);
+/// ```
+pub fn cast_scalar(scalar: &dyn Scalar, to_type: &DataType) -> Result<Box<dyn Scalar>> {
+    if !can_cast_types(scalar.data_type(), to_type) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Casting scalar from {:?} to {:?} is not supported",
+            scalar.data_type(),
+            to_type
+        )));
+    }
+    let array = scalar.to_array();
+    let array = cast(array.as_ref(), to_type, CastOptions::default())?;
+    Ok(new_scalar(array.as_ref(), 0))
+}
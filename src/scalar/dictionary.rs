@@ -1,9 +1,10 @@
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::{array::*, datatypes::DataType};
 
-use super::Scalar;
+use super::{hash_scalar, Scalar, NULL_HASH_SENTINEL};
 
 /// The [`DictionaryArray`] equivalent of [`Array`] for [`Scalar`].
 #[derive(Debug, Clone)]
@@ -52,4 +53,46 @@ impl<K: DictionaryKey> Scalar for DictionaryScalar<K> {
     fn data_type(&self) -> &DataType {
         &self.data_type
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        match &self.value {
+            Some(value) => {
+                let keys = PrimitiveArray::<K>::new(
+                    K::PRIMITIVE.into(),
+                    vec![K::default(); length].into(),
+                    None,
+                );
+                Box::new(
+                    DictionaryArray::<K>::from_data(keys, value.to_array().into())
+                        .to(self.data_type.clone()),
+                )
+            }
+            None => Box::new(
+                DictionaryArray::<K>::new_null(self.data_type.clone(), length)
+                    .to(self.data_type.clone()),
+            ),
+        }
+    }
+}
+
+impl<K: DictionaryKey> std::fmt::Display for DictionaryScalar<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{}", value.as_ref()),
+            None => f.write_str("null"),
+        }
+    }
+}
+
+impl<K: DictionaryKey> Hash for DictionaryScalar<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.value {
+            Some(value) => hash_scalar(value.as_ref(), state),
+            None => NULL_HASH_SENTINEL.hash(state),
+        }
+    }
 }
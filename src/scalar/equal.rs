@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
+use num_traits::Float;
+
 use super::*;
-use crate::datatypes::PhysicalType;
+use crate::datatypes::{PhysicalType, PrimitiveType};
+use crate::types::NativeType;
 
 impl PartialEq for dyn Scalar + '_ {
     fn eq(&self, that: &dyn Scalar) -> bool {
@@ -9,6 +12,11 @@ impl PartialEq for dyn Scalar + '_ {
     }
 }
 
+/// [`equal`] compares floats bit by bit (see [`float_total_eq`]), so that a scalar holding
+/// `NaN` is equal to itself. Valid union and map scalars, which are not supported, are the
+/// exception: they are not equal to anything.
+impl Eq for dyn Scalar + '_ {}
+
 impl PartialEq<dyn Scalar> for Arc<dyn Scalar + '_> {
     fn eq(&self, that: &dyn Scalar) -> bool {
         equal(&**self, that)
@@ -29,15 +37,39 @@ macro_rules! dyn_eq {
     }};
 }
 
-fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
+/// Returns whether two [`Scalar`]s are equal.
+/// Scalars of different [`DataType`]s are never equal. Valid scalars of union and map types
+/// are not supported and are never equal.
+///
+/// Floats, including those nested in lists and structs, are compared via [`float_total_eq`]:
+/// `NaN` is equal to `NaN` and `0.0` is not equal to `-0.0`. This makes [`equal`] an
+/// equivalence relation, so that scalars can be used as keys of a
+/// [`HashMap`](std::collections::HashMap).
+/// # Example
+/// ```
+/// use arrow2::scalar::{equal, PrimitiveScalar, Utf8Scalar};
+///
+/// let a = PrimitiveScalar::from(Some(1i32));
+/// let b = PrimitiveScalar::from(Some(1i32));
+/// let c = Utf8Scalar::<i32>::from(Some("1"));
+/// assert!(equal(&a, &b));
+/// assert!(!equal(&a, &c));
+/// ```
+pub fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
     if lhs.data_type() != rhs.data_type() {
         return false;
     }
+    // a (typed) `NullScalar` is equal to any null of the same type
+    if lhs.as_any().is::<NullScalar>() || rhs.as_any().is::<NullScalar>() {
+        return !lhs.is_valid() && !rhs.is_valid();
+    }
 
     use PhysicalType::*;
     match lhs.data_type().to_physical_type() {
         Null => dyn_eq!(NullScalar, lhs, rhs),
         Boolean => dyn_eq!(BooleanScalar, lhs, rhs),
+        Primitive(PrimitiveType::Float32) => float_eq::<f32>(lhs, rhs),
+        Primitive(PrimitiveType::Float64) => float_eq::<f64>(lhs, rhs),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             dyn_eq!(PrimitiveScalar<$T>, lhs, rhs)
         }),
@@ -45,15 +77,91 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
         LargeUtf8 => dyn_eq!(Utf8Scalar<i64>, lhs, rhs),
         Binary => dyn_eq!(BinaryScalar<i32>, lhs, rhs),
         LargeBinary => dyn_eq!(BinaryScalar<i64>, lhs, rhs),
-        List => dyn_eq!(ListScalar<i32>, lhs, rhs),
-        LargeList => dyn_eq!(ListScalar<i64>, lhs, rhs),
+        List => list_eq::<i32>(lhs, rhs),
+        LargeList => list_eq::<i64>(lhs, rhs),
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
-            dyn_eq!(DictionaryScalar<$T>, lhs, rhs)
+            let lhs = lhs.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+            match (lhs.value(), rhs.value()) {
+                (Some(lhs), Some(rhs)) => equal(lhs.as_ref(), rhs.as_ref()),
+                (None, None) => true,
+                _ => false,
+            }
         }),
-        Struct => dyn_eq!(StructScalar, lhs, rhs),
+        Struct => {
+            let lhs = lhs.as_any().downcast_ref::<StructScalar>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<StructScalar>().unwrap();
+            lhs.is_valid() == rhs.is_valid()
+                && (!lhs.is_valid()
+                    || (lhs.values().len() == rhs.values().len()
+                        && lhs
+                            .values()
+                            .iter()
+                            .zip(rhs.values().iter())
+                            .all(|(lhs, rhs)| equal(lhs.as_ref(), rhs.as_ref()))))
+        }
         FixedSizeBinary => dyn_eq!(FixedSizeBinaryScalar, lhs, rhs),
-        FixedSizeList => dyn_eq!(FixedSizeListScalar, lhs, rhs),
-        Union => unimplemented!("{:?}", Union),
-        Map => unimplemented!("{:?}", Map),
+        FixedSizeList => {
+            let lhs = lhs.as_any().downcast_ref::<FixedSizeListScalar>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<FixedSizeListScalar>().unwrap();
+            match (lhs.values(), rhs.values()) {
+                (Some(lhs), Some(rhs)) => array_eq(lhs.as_ref(), rhs.as_ref()),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+        // there is no concrete scalar of these types to compare
+        Union | Map => false,
+    }
+}
+
+fn float_eq<T: NativeType + Float>(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    float_total_eq(lhs, rhs)
+}
+
+fn list_eq<O: Offset>(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
+    let lhs = lhs.as_any().downcast_ref::<ListScalar<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<ListScalar<O>>().unwrap();
+    lhs.is_valid() == rhs.is_valid()
+        && (!lhs.is_valid() || array_eq(lhs.values().as_ref(), rhs.values().as_ref()))
+}
+
+/// Compares two arrays entry by entry via [`equal`], consistently with `hash_array`.
+fn array_eq(lhs: &dyn Array, rhs: &dyn Array) -> bool {
+    lhs.len() == rhs.len()
+        && (0..lhs.len()).all(|i| equal(new_scalar(lhs, i).as_ref(), new_scalar(rhs, i).as_ref()))
+}
+
+/// Returns whether two floating point [`PrimitiveScalar`]s are equal bit by bit,
+/// e.g. to deduplicate them.
+///
+/// Unlike `==` on [`PrimitiveScalar`], `NaN` is equal to `NaN` (regardless of its payload)
+/// and `0.0` is not equal to `-0.0`. This is how [`equal`] compares floats.
+/// # Example
+/// ```
+/// use arrow2::scalar::{float_total_eq, PrimitiveScalar};
+///
+/// let nan = PrimitiveScalar::from(Some(f64::NAN));
+/// assert!(float_total_eq(&nan, &nan));
+/// let zero = PrimitiveScalar::from(Some(0.0f64));
+/// let negative_zero = PrimitiveScalar::from(Some(-0.0f64));
+/// assert!(!float_total_eq(&zero, &negative_zero));
+/// ```
+pub fn float_total_eq<T: NativeType + Float>(
+    lhs: &PrimitiveScalar<T>,
+    rhs: &PrimitiveScalar<T>,
+) -> bool {
+    if lhs.data_type() != rhs.data_type() {
+        return false;
+    }
+    match (lhs.value(), rhs.value()) {
+        (Some(lhs), Some(rhs)) => {
+            (lhs.is_nan() && rhs.is_nan())
+                || lhs.to_ne_bytes().as_ref() == rhs.to_ne_bytes().as_ref()
+        }
+        (None, None) => true,
+        _ => false,
     }
 }
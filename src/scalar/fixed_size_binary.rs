@@ -1,6 +1,11 @@
-use crate::datatypes::DataType;
+use std::hash::{Hash, Hasher};
 
-use super::Scalar;
+use crate::{
+    array::{Array, FixedSizeBinaryArray},
+    datatypes::DataType,
+};
+
+use super::{Scalar, NULL_HASH_SENTINEL};
 
 #[derive(Debug, Clone, PartialEq)]
 /// The [`Scalar`] implementation of fixed size binary ([`Option<Box<[u8]>>`]).
@@ -24,10 +29,7 @@ impl FixedSizeBinaryScalar {
         Self {
             value: value.map(|x| {
                 let x: Vec<u8> = x.into();
-                assert_eq!(
-                    data_type.to_logical_type(),
-                    &DataType::FixedSizeBinary(x.len())
-                );
+                assert_eq!(FixedSizeBinaryArray::get_size(&data_type), x.len());
                 x.into_boxed_slice()
             }),
             data_type,
@@ -56,4 +58,40 @@ impl Scalar for FixedSizeBinaryScalar {
     fn data_type(&self) -> &DataType {
         &self.data_type
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        match &self.value {
+            Some(value) => Box::new(FixedSizeBinaryArray::new(
+                self.data_type.clone(),
+                value.repeat(length).into(),
+                None,
+            )),
+            None => Box::new(FixedSizeBinaryArray::new_null(
+                self.data_type.clone(),
+                length,
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for FixedSizeBinaryScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value() {
+            Some(value) => write!(f, "{:?}", value),
+            None => f.write_str("null"),
+        }
+    }
+}
+
+impl Hash for FixedSizeBinaryScalar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.value {
+            Some(value) => value.hash(state),
+            None => NULL_HASH_SENTINEL.hash(state),
+        }
+    }
 }
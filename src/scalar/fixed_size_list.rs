@@ -1,9 +1,10 @@
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::{array::*, datatypes::DataType};
 
-use super::Scalar;
+use super::{hash::hash_array, repeat, Scalar, NULL_HASH_SENTINEL};
 
 /// The scalar equivalent of [`FixedSizeListArray`]. Like [`FixedSizeListArray`], this struct holds a dynamically-typed
 /// [`Array`]. The only difference is that this has only one element.
@@ -58,4 +59,37 @@ impl Scalar for FixedSizeListScalar {
     fn data_type(&self) -> &DataType {
         &self.data_type
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        match &self.values {
+            Some(values) => Box::new(FixedSizeListArray::new(
+                self.data_type.clone(),
+                repeat(values.as_ref(), length).into(),
+                None,
+            )),
+            None => Box::new(FixedSizeListArray::new_null(self.data_type.clone(), length)),
+        }
+    }
+}
+
+impl std::fmt::Display for FixedSizeListScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.values {
+            Some(values) => super::write_array(f, values.as_ref()),
+            None => f.write_str("null"),
+        }
+    }
+}
+
+impl Hash for FixedSizeListScalar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.values {
+            Some(values) => hash_array(values.as_ref(), state),
+            None => NULL_HASH_SENTINEL.hash(state),
+        }
+    }
 }
@@ -0,0 +1,75 @@
+use std::hash::{Hash, Hasher};
+
+use super::*;
+use crate::datatypes::PhysicalType;
+
+impl Hash for dyn Scalar + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_scalar(self, state)
+    }
+}
+
+macro_rules! dyn_hash {
+    ($ty:ty, $scalar:expr, $state:expr) => {{
+        let scalar = $scalar.as_any().downcast_ref::<$ty>().unwrap();
+        scalar.hash($state)
+    }};
+}
+
+/// Feeds a [`Scalar`] into `state`.
+///
+/// This function agrees with [`equal`]: two scalars that are equal have the same hash,
+/// so that scalars can be used as keys of a [`HashMap`](std::collections::HashMap),
+/// e.g. to group by them. Floats are hashed via their bit pattern (every `NaN` alike)
+/// and every null is hashed to the same sentinel. Valid scalars of union and map types are
+/// hashed via their [`DataType`] alone.
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use arrow2::scalar::{PrimitiveScalar, Scalar};
+///
+/// let mut groups = HashMap::<Box<dyn Scalar>, usize>::new();
+/// for value in [Some(1i32), None, Some(1), None, Some(2)] {
+///     let scalar = Box::new(PrimitiveScalar::from(value)) as Box<dyn Scalar>;
+///     *groups.entry(scalar).or_default() += 1;
+/// }
+/// let one = Box::new(PrimitiveScalar::from(Some(1i32))) as Box<dyn Scalar>;
+/// assert_eq!(groups.len(), 3);
+/// assert_eq!(groups[&one], 2);
+/// ```
+pub fn hash_scalar<H: Hasher>(scalar: &dyn Scalar, state: &mut H) {
+    // nulls are equal regardless of their concrete scalar (e.g. a typed `NullScalar`)
+    if !scalar.is_valid() {
+        NULL_HASH_SENTINEL.hash(state);
+        return;
+    }
+
+    use PhysicalType::*;
+    match scalar.data_type().to_physical_type() {
+        Null => dyn_hash!(NullScalar, scalar, state),
+        Boolean => dyn_hash!(BooleanScalar, scalar, state),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            dyn_hash!(PrimitiveScalar<$T>, scalar, state)
+        }),
+        Utf8 => dyn_hash!(Utf8Scalar<i32>, scalar, state),
+        LargeUtf8 => dyn_hash!(Utf8Scalar<i64>, scalar, state),
+        Binary => dyn_hash!(BinaryScalar<i32>, scalar, state),
+        LargeBinary => dyn_hash!(BinaryScalar<i64>, scalar, state),
+        List => dyn_hash!(ListScalar<i32>, scalar, state),
+        LargeList => dyn_hash!(ListScalar<i64>, scalar, state),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            dyn_hash!(DictionaryScalar<$T>, scalar, state)
+        }),
+        Struct => dyn_hash!(StructScalar, scalar, state),
+        FixedSizeBinary => dyn_hash!(FixedSizeBinaryScalar, scalar, state),
+        FixedSizeList => dyn_hash!(FixedSizeListScalar, scalar, state),
+        // there is no concrete scalar of these types: hash their type only
+        Union | Map => scalar.data_type().hash(state),
+    }
+}
+
+/// Feeds every entry of `array` into `state`, consistently with [`hash_scalar`].
+pub(super) fn hash_array<H: Hasher>(array: &dyn Array, state: &mut H) {
+    array.len().hash(state);
+    (0..array.len()).for_each(|i| hash_scalar(new_scalar(array, i).as_ref(), state));
+}
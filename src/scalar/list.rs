@@ -1,9 +1,10 @@
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::{array::*, datatypes::DataType};
 
-use super::Scalar;
+use super::{hash::hash_array, repeat, Scalar, NULL_HASH_SENTINEL};
 
 /// The scalar equivalent of [`ListArray`]. Like [`ListArray`], this struct holds a dynamically-typed
 /// [`Array`]. The only difference is that this has only one element.
@@ -65,4 +66,44 @@ impl<O: Offset> Scalar for ListScalar<O> {
     fn data_type(&self) -> &DataType {
         &self.data_type
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        if !self.is_valid {
+            return Box::new(ListArray::<O>::new_null(self.data_type.clone(), length));
+        }
+        let size = self.values.len();
+        let offsets = (0..=length)
+            .map(|i| O::from_usize(i * size).expect("The offsets overflow"))
+            .collect::<Vec<_>>();
+        Box::new(ListArray::<O>::new(
+            self.data_type.clone(),
+            offsets.into(),
+            repeat(self.values.as_ref(), length).into(),
+            None,
+        ))
+    }
+}
+
+impl<O: Offset> std::fmt::Display for ListScalar<O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_valid {
+            super::write_array(f, self.values.as_ref())
+        } else {
+            f.write_str("null")
+        }
+    }
+}
+
+impl<O: Offset> Hash for ListScalar<O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.is_valid {
+            hash_array(self.values.as_ref(), state)
+        } else {
+            NULL_HASH_SENTINEL.hash(state)
+        }
+    }
 }
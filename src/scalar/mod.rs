@@ -7,6 +7,10 @@ use crate::{array::*, datatypes::*};
 mod dictionary;
 pub use dictionary::*;
 mod equal;
+pub use equal::{equal, float_total_eq};
+mod hash;
+pub use hash::hash_scalar;
+pub mod ord;
 mod primitive;
 pub use primitive::*;
 mod utf8;
@@ -25,6 +29,15 @@ mod fixed_size_list;
 pub use fixed_size_list::*;
 mod fixed_size_binary;
 pub use fixed_size_binary::*;
+#[cfg(feature = "compute_cast")]
+mod cast;
+#[cfg(feature = "serde_types")]
+mod serialization;
+#[cfg(feature = "compute_cast")]
+pub use cast::cast_scalar;
+
+/// The value hashed by every null [`Scalar`], so that nulls of a given type hash equally.
+const NULL_HASH_SENTINEL: u64 = 0x9e37_79b9_7f4a_7c15;
 
 /// Trait object declaring an optional value with a [`DataType`].
 /// This strait is often used in APIs that accept multiple scalar types.
@@ -37,6 +50,93 @@ pub trait Scalar: std::fmt::Debug + Send + Sync {
 
     /// the logical type.
     fn data_type(&self) -> &DataType;
+
+    /// Clones this [`Scalar`] into a new [`Box`]ed trait object.
+    ///
+    /// The default implementation round-trips through [`Scalar::to_array`] and [`new_scalar`],
+    /// and thus returns one of arrow2's concrete scalars of the same [`DataType`].
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        new_scalar(self.to_array().as_ref(), 0)
+    }
+
+    /// Returns an [`Array`] of `length` entries, all equal to this [`Scalar`].
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array>;
+
+    /// Returns an [`Array`] with a single entry equal to this [`Scalar`].
+    fn to_array(&self) -> Box<dyn Array> {
+        self.to_boxed_array(1)
+    }
+}
+
+/// Returns an [`Array`] with the values of `array` repeated `times` times.
+fn repeat(array: &dyn Array, times: usize) -> Box<dyn Array> {
+    let mut growable = growable::make_growable(&[array], false, array.len() * times);
+    (0..times).for_each(|_| growable.extend(0, 0, array.len()));
+    growable.as_box()
+}
+
+impl Clone for Box<dyn Scalar> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+macro_rules! fmt_dyn {
+    ($scalar:expr, $ty:ty, $f:expr) => {{
+        let scalar = $scalar.as_any().downcast_ref::<$ty>().unwrap();
+        std::fmt::Display::fmt(scalar, $f)
+    }};
+}
+
+/// Writes a [`Scalar`] to `f` in a human-readable format, writing `null` when it is not valid.
+/// Scalars of union and map types are written as a placeholder with their [`DataType`].
+pub fn fmt_scalar(scalar: &dyn Scalar, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    // a `NullScalar` may be of any type
+    if scalar.as_any().is::<NullScalar>() {
+        return fmt_dyn!(scalar, NullScalar, f);
+    }
+    use PhysicalType::*;
+    match scalar.data_type().to_physical_type() {
+        Null => fmt_dyn!(scalar, NullScalar, f),
+        Boolean => fmt_dyn!(scalar, BooleanScalar, f),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            fmt_dyn!(scalar, PrimitiveScalar<$T>, f)
+        }),
+        Utf8 => fmt_dyn!(scalar, Utf8Scalar<i32>, f),
+        LargeUtf8 => fmt_dyn!(scalar, Utf8Scalar<i64>, f),
+        Binary => fmt_dyn!(scalar, BinaryScalar<i32>, f),
+        LargeBinary => fmt_dyn!(scalar, BinaryScalar<i64>, f),
+        List => fmt_dyn!(scalar, ListScalar<i32>, f),
+        LargeList => fmt_dyn!(scalar, ListScalar<i64>, f),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            fmt_dyn!(scalar, DictionaryScalar<$T>, f)
+        }),
+        Struct => fmt_dyn!(scalar, StructScalar, f),
+        FixedSizeBinary => fmt_dyn!(scalar, FixedSizeBinaryScalar, f),
+        FixedSizeList => fmt_dyn!(scalar, FixedSizeListScalar, f),
+        // there is no concrete scalar of these types
+        Union | Map => write!(f, "<{:?} scalar>", scalar.data_type()),
+    }
+}
+
+impl std::fmt::Display for dyn Scalar + '_ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_scalar(self, f)
+    }
+}
+
+/// Writes all values of `array` to `f` as `[a, b, c]`.
+fn write_array(f: &mut std::fmt::Formatter<'_>, array: &dyn Array) -> std::fmt::Result {
+    use std::fmt::Write;
+    let display = get_display(array, "null");
+    f.write_char('[')?;
+    for index in 0..array.len() {
+        if index != 0 {
+            f.write_str(", ")?;
+        }
+        display(f, index)?;
+    }
+    f.write_char(']')
 }
 
 macro_rules! dyn_new_utf8 {
@@ -162,3 +262,10 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
         }),
     }
 }
+
+/// Creates a new [`Scalar`] from the first entry of an [`Array`].
+/// # Panics
+/// This function panics iff `array` is empty.
+pub fn first_scalar(array: &dyn Array) -> Box<dyn Scalar> {
+    new_scalar(array, 0)
+}
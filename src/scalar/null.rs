@@ -1,16 +1,43 @@
-use crate::datatypes::DataType;
+use std::hash::{Hash, Hasher};
 
-use super::Scalar;
+use crate::{
+    array::{new_null_array, Array},
+    datatypes::DataType,
+};
+
+use super::{Scalar, NULL_HASH_SENTINEL};
 
 /// The representation of a single entry of a [`crate::array::NullArray`].
+/// It can also represent a null of any other [`DataType`] via [`NullScalar::new_typed`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct NullScalar {}
+pub struct NullScalar {
+    data_type: DataType,
+}
 
 impl NullScalar {
-    /// A new [`NullScalar`]
+    /// A new [`NullScalar`] of [`DataType::Null`]
     #[inline]
     pub fn new() -> Self {
-        Self {}
+        Self::new_typed(DataType::Null)
+    }
+
+    /// A new [`NullScalar`] of `data_type`, representing a null of that type.
+    /// # Example
+    /// ```
+    /// use arrow2::datatypes::DataType;
+    /// use arrow2::scalar::{NullScalar, PrimitiveScalar, Scalar};
+    ///
+    /// let scalar = NullScalar::new_typed(DataType::Int32);
+    /// assert_eq!(scalar.data_type(), &DataType::Int32);
+    /// assert!(!scalar.is_valid());
+    ///
+    /// // it is equal to any other null of the same type
+    /// let null = PrimitiveScalar::<i32>::from(None);
+    /// assert_eq!(&scalar as &dyn Scalar, &null as &dyn Scalar);
+    /// ```
+    #[inline]
+    pub fn new_typed(data_type: DataType) -> Self {
+        Self { data_type }
     }
 }
 
@@ -33,6 +60,33 @@ impl Scalar for NullScalar {
 
     #[inline]
     fn data_type(&self) -> &DataType {
-        &DataType::Null
+        &self.data_type
+    }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        new_null_array(self.data_type.clone(), length)
+    }
+}
+
+impl Hash for NullScalar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        NULL_HASH_SENTINEL.hash(state)
+    }
+}
+
+impl PartialOrd for NullScalar {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (self.data_type == other.data_type).then(|| std::cmp::Ordering::Equal)
+    }
+}
+
+impl std::fmt::Display for NullScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("null")
     }
 }
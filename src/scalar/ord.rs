@@ -0,0 +1,136 @@
+//! Contains functions to order [`Scalar`]s.
+use std::cmp::Ordering;
+
+use crate::array::ord::{total_cmp_f32, total_cmp_f64};
+use crate::datatypes::{PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+
+use super::*;
+
+macro_rules! dyn_ord {
+    ($ty:ty, $lhs:expr, $rhs:expr) => {{
+        let lhs = $lhs.as_any().downcast_ref::<$ty>().unwrap();
+        let rhs = $rhs.as_any().downcast_ref::<$ty>().unwrap();
+        lhs.value().cmp(&rhs.value())
+    }};
+}
+
+macro_rules! dyn_ord_float {
+    ($ty:ty, $lhs:expr, $rhs:expr, $cmp:expr) => {{
+        let lhs = $lhs
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<$ty>>()
+            .unwrap();
+        let rhs = $rhs
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<$ty>>()
+            .unwrap();
+        $cmp(&lhs.value().unwrap(), &rhs.value().unwrap())
+    }};
+}
+
+/// Compares two valid [`Scalar`]s of the same [`DataType`].
+fn compare_values(lhs: &dyn Scalar, rhs: &dyn Scalar) -> Result<Ordering> {
+    use PhysicalType::*;
+    Ok(match lhs.data_type().to_physical_type() {
+        Null => Ordering::Equal,
+        Boolean => dyn_ord!(BooleanScalar, lhs, rhs),
+        Primitive(PrimitiveType::Int8) => dyn_ord!(PrimitiveScalar<i8>, lhs, rhs),
+        Primitive(PrimitiveType::Int16) => dyn_ord!(PrimitiveScalar<i16>, lhs, rhs),
+        Primitive(PrimitiveType::Int32) => dyn_ord!(PrimitiveScalar<i32>, lhs, rhs),
+        Primitive(PrimitiveType::Int64) => dyn_ord!(PrimitiveScalar<i64>, lhs, rhs),
+        Primitive(PrimitiveType::Int128) => dyn_ord!(PrimitiveScalar<i128>, lhs, rhs),
+        Primitive(PrimitiveType::UInt8) => dyn_ord!(PrimitiveScalar<u8>, lhs, rhs),
+        Primitive(PrimitiveType::UInt16) => dyn_ord!(PrimitiveScalar<u16>, lhs, rhs),
+        Primitive(PrimitiveType::UInt32) => dyn_ord!(PrimitiveScalar<u32>, lhs, rhs),
+        Primitive(PrimitiveType::UInt64) => dyn_ord!(PrimitiveScalar<u64>, lhs, rhs),
+        Primitive(PrimitiveType::Float32) => dyn_ord_float!(f32, lhs, rhs, total_cmp_f32),
+        Primitive(PrimitiveType::Float64) => dyn_ord_float!(f64, lhs, rhs, total_cmp_f64),
+        Utf8 => dyn_ord!(Utf8Scalar<i32>, lhs, rhs),
+        LargeUtf8 => dyn_ord!(Utf8Scalar<i64>, lhs, rhs),
+        Binary => dyn_ord!(BinaryScalar<i32>, lhs, rhs),
+        LargeBinary => dyn_ord!(BinaryScalar<i64>, lhs, rhs),
+        FixedSizeBinary => dyn_ord!(FixedSizeBinaryScalar, lhs, rhs),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The data type {:?} has no natural order",
+                lhs.data_type()
+            )))
+        }
+    })
+}
+
+/// Compares two [`Scalar`]s, ordering nulls before any valid value.
+/// # Error
+/// This function errors when the scalars' [`DataType`] differ or when the type has no natural order.
+/// # Example
+/// ```
+/// use std::cmp::Ordering;
+/// use arrow2::scalar::{ord::compare, PrimitiveScalar};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let a = PrimitiveScalar::from(Some(1i32));
+/// let b = PrimitiveScalar::from(Some(2i32));
+/// let null = PrimitiveScalar::<i32>::from(None);
+///
+/// assert_eq!(compare(&a, &b)?, Ordering::Less);
+/// assert_eq!(compare(&null, &a)?, Ordering::Less);
+/// # Ok(())
+/// # }
+/// ```
+pub fn compare(lhs: &dyn Scalar, rhs: &dyn Scalar) -> Result<Ordering> {
+    compare_with_nulls(lhs, rhs, true)
+}
+
+/// Compares two [`Scalar`]s, ordering nulls before (`nulls_first = true`) or after
+/// (`nulls_first = false`) any valid value. Two nulls are equal.
+/// # Error
+/// This function errors when the scalars' [`DataType`] differ or when the type has no natural order.
+pub fn compare_with_nulls(
+    lhs: &dyn Scalar,
+    rhs: &dyn Scalar,
+    nulls_first: bool,
+) -> Result<Ordering> {
+    if lhs.data_type() != rhs.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Can't compare scalars of different types".to_string(),
+        ));
+    }
+
+    match (lhs.is_valid(), rhs.is_valid()) {
+        (true, true) => compare_values(lhs, rhs),
+        (false, false) => Ok(Ordering::Equal),
+        (false, true) => Ok(if nulls_first {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }),
+        (true, false) => Ok(if nulls_first {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }),
+    }
+}
+
+/// Compares two [`Scalar`]s of the same [`DataType`], ordering nulls after any valid value.
+/// This is the same order used by the [`PartialOrd`] implementations of the concrete scalars.
+/// # Error
+/// This function errors when the scalars' [`DataType`] differ or when the type has no natural order.
+pub fn cmp_scalar(lhs: &dyn Scalar, rhs: &dyn Scalar) -> Result<Ordering> {
+    compare_with_nulls(lhs, rhs, false)
+}
+
+/// Partially orders two optional values, ordering `None` after any `Some`.
+#[inline]
+pub(super) fn partial_cmp_nulls_last<T: PartialOrd>(
+    lhs: Option<T>,
+    rhs: Option<T>,
+) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => lhs.partial_cmp(&rhs),
+        (None, None) => Some(Ordering::Equal),
+        (None, Some(_)) => Some(Ordering::Greater),
+        (Some(_), None) => Some(Ordering::Less),
+    }
+}
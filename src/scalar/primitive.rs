@@ -1,9 +1,29 @@
-use crate::{datatypes::DataType, error::ArrowError, types::NativeType};
+use std::hash::{Hash, Hasher};
 
-use super::Scalar;
+use crate::{
+    array::{
+        ord::{total_cmp_f32, total_cmp_f64},
+        Array, PrimitiveArray,
+    },
+    datatypes::DataType,
+    error::ArrowError,
+    types::NativeType,
+};
+
+use super::{ord::partial_cmp_nulls_last, Scalar, NULL_HASH_SENTINEL};
 
 /// The implementation of [`Scalar`] for primitive, semantically equivalent to [`Option<T>`]
 /// with [`DataType`].
+/// # Example
+/// ```
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::{PrimitiveScalar, Scalar};
+///
+/// // the `DataType` is inferred from `T`
+/// let scalar = PrimitiveScalar::from(Some(1.5f64));
+/// assert_eq!(scalar.value(), Some(1.5));
+/// assert_eq!(scalar.data_type(), &DataType::Float64);
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct PrimitiveScalar<T: NativeType> {
     value: Option<T>,
@@ -39,7 +59,60 @@ impl<T: NativeType> PrimitiveScalar<T> {
     }
 }
 
+impl PrimitiveScalar<i128> {
+    /// Returns the precision of this scalar, if its [`DataType`] is [`DataType::Decimal`].
+    #[inline]
+    pub fn precision(&self) -> Option<usize> {
+        match self.data_type.to_logical_type() {
+            DataType::Decimal(precision, _) => Some(*precision),
+            _ => None,
+        }
+    }
+
+    /// Returns the scale of this scalar, if its [`DataType`] is [`DataType::Decimal`].
+    #[inline]
+    pub fn scale(&self) -> Option<usize> {
+        match self.data_type.to_logical_type() {
+            DataType::Decimal(_, scale) => Some(*scale),
+            _ => None,
+        }
+    }
+
+    /// Returns the value rendered as a decimal number with [`Self::scale`] fractional digits,
+    /// e.g. `-12345` with scale 2 is rendered as `"-123.45"`.
+    /// Returns `None` if the scalar is null or its [`DataType`] is not [`DataType::Decimal`].
+    /// # Example
+    /// ```
+    /// use arrow2::datatypes::DataType;
+    /// use arrow2::scalar::PrimitiveScalar;
+    ///
+    /// let scalar = PrimitiveScalar::new(DataType::Decimal(5, 2), Some(-105i128));
+    /// assert_eq!(scalar.to_string_scaled(), Some("-1.05".to_string()));
+    /// ```
+    pub fn to_string_scaled(&self) -> Option<String> {
+        let scale = self.scale()?;
+        let value = self.value?;
+
+        let digits = value.unsigned_abs().to_string();
+        let digits = if digits.len() <= scale {
+            // pad with zeros so that there is at least one integer digit
+            format!("{:0>width$}", digits, width = scale + 1)
+        } else {
+            digits
+        };
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let sign = if value < 0 { "-" } else { "" };
+        Some(if scale == 0 {
+            format!("{}{}", sign, integer)
+        } else {
+            format!("{}{}.{}", sign, integer, fraction)
+        })
+    }
+}
+
 impl<T: NativeType> From<Option<T>> for PrimitiveScalar<T> {
+    /// Returns a new [`PrimitiveScalar`] whose [`DataType`] is the default of `T`,
+    /// i.e. `T::PRIMITIVE.into()`.
     #[inline]
     fn from(v: Option<T>) -> Self {
         Self::new(T::PRIMITIVE.into(), v)
@@ -61,4 +134,90 @@ impl<T: NativeType> Scalar for PrimitiveScalar<T> {
     fn data_type(&self) -> &DataType {
         &self.data_type
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        match self.value {
+            Some(value) => Box::new(PrimitiveArray::<T>::new(
+                self.data_type.clone(),
+                vec![value; length].into(),
+                None,
+            )),
+            None => Box::new(PrimitiveArray::<T>::new_null(
+                self.data_type.clone(),
+                length,
+            )),
+        }
+    }
+}
+
+impl<T: NativeType> Hash for PrimitiveScalar<T> {
+    #[allow(clippy::eq_op)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data_type.hash(state);
+        match self.value {
+            // values equal to the default (e.g. `-0.0` and `0.0`) must hash equally
+            Some(value) if value == T::default() => T::default().to_le_bytes().as_ref().hash(state),
+            // every `NaN` is equal to every other `NaN` (see `equal`)
+            Some(value) if value != value => "NaN".hash(state),
+            Some(value) => value.to_le_bytes().as_ref().hash(state),
+            None => NULL_HASH_SENTINEL.hash(state),
+        }
+    }
+}
+
+macro_rules! partial_ord {
+    ($type:ty) => {
+        impl PartialOrd for PrimitiveScalar<$type> {
+            /// Orders by value, with nulls last. Returns `None` when the [`DataType`]s differ.
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                if self.data_type != other.data_type {
+                    return None;
+                }
+                partial_cmp_nulls_last(self.value, other.value)
+            }
+        }
+    };
+    ($type:ty, $cmp:expr) => {
+        impl PartialOrd for PrimitiveScalar<$type> {
+            /// Orders by value via [`total_cmp_f32`] or [`total_cmp_f64`] (e.g. `NaN` is
+            /// greater than any other value), with nulls last, like [`cmp_scalar`](super::ord::cmp_scalar).
+            /// Returns `None` when the [`DataType`]s differ.
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                if self.data_type != other.data_type {
+                    return None;
+                }
+                match (self.value, other.value) {
+                    (Some(lhs), Some(rhs)) => Some($cmp(&lhs, &rhs)),
+                    (lhs, rhs) => partial_cmp_nulls_last(lhs, rhs),
+                }
+            }
+        }
+    };
+}
+
+partial_ord!(i8);
+partial_ord!(i16);
+partial_ord!(i32);
+partial_ord!(i64);
+partial_ord!(i128);
+partial_ord!(u8);
+partial_ord!(u16);
+partial_ord!(u32);
+partial_ord!(u64);
+partial_ord!(f32, total_cmp_f32);
+partial_ord!(f64, total_cmp_f64);
+
+impl<T: NativeType> std::fmt::Display for PrimitiveScalar<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            Some(value) => write!(f, "{}", value),
+            None => f.write_str("null"),
+        }
+    }
 }
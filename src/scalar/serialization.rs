@@ -0,0 +1,367 @@
+//! (De)serialization of [`Scalar`]s via [`serde`].
+//!
+//! A scalar is represented by its [`DataType`] (the tag) and its value,
+//! e.g. `{"data_type":"Int32","value":1}`. Invalid scalars have the value `null`.
+use std::sync::Arc;
+
+use serde::de::{Deserializer, Error as DeError};
+use serde::ser::{Error as SerError, Serializer};
+use serde::{Deserialize as _, Serialize as _};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::array::{growable::make_growable, new_empty_array, new_null_array, Array};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::{days_ms, months_days_ns, NativeType, Offset};
+
+use super::*;
+
+#[derive(Serialize, Deserialize)]
+struct Tagged {
+    data_type: DataType,
+    value: Value,
+}
+
+/// The value of a [`Scalar`], stripped from its [`DataType`].
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Seq(Vec<Value>),
+}
+
+macro_rules! dyn_value {
+    ($scalar:expr, $ty:ty) => {
+        $scalar.as_any().downcast_ref::<$ty>().unwrap().value()
+    };
+}
+
+fn binary_value(value: &[u8]) -> Value {
+    Value::Seq(value.iter().map(|x| Value::UInt(*x as u64)).collect())
+}
+
+fn array_value(array: &dyn Array) -> Result<Value> {
+    (0..array.len())
+        .map(|i| to_value(new_scalar(array, i).as_ref()))
+        .collect::<Result<Vec<_>>>()
+        .map(Value::Seq)
+}
+
+fn to_value(scalar: &dyn Scalar) -> Result<Value> {
+    if !scalar.is_valid() {
+        return Ok(Value::Null);
+    }
+    use PhysicalType::*;
+    Ok(match scalar.data_type().to_physical_type() {
+        Null => Value::Null,
+        Boolean => Value::Bool(dyn_value!(scalar, BooleanScalar).unwrap()),
+        Primitive(primitive) => {
+            use PrimitiveType::*;
+            match primitive {
+                Int8 => Value::Int(dyn_value!(scalar, PrimitiveScalar<i8>).unwrap() as i64),
+                Int16 => Value::Int(dyn_value!(scalar, PrimitiveScalar<i16>).unwrap() as i64),
+                Int32 => Value::Int(dyn_value!(scalar, PrimitiveScalar<i32>).unwrap() as i64),
+                Int64 => Value::Int(dyn_value!(scalar, PrimitiveScalar<i64>).unwrap()),
+                // i128 does not fit in every format: use its decimal representation
+                Int128 => Value::String(
+                    dyn_value!(scalar, PrimitiveScalar<i128>)
+                        .unwrap()
+                        .to_string(),
+                ),
+                UInt8 => Value::UInt(dyn_value!(scalar, PrimitiveScalar<u8>).unwrap() as u64),
+                UInt16 => Value::UInt(dyn_value!(scalar, PrimitiveScalar<u16>).unwrap() as u64),
+                UInt32 => Value::UInt(dyn_value!(scalar, PrimitiveScalar<u32>).unwrap() as u64),
+                UInt64 => Value::UInt(dyn_value!(scalar, PrimitiveScalar<u64>).unwrap()),
+                Float32 => Value::Float(dyn_value!(scalar, PrimitiveScalar<f32>).unwrap() as f64),
+                Float64 => Value::Float(dyn_value!(scalar, PrimitiveScalar<f64>).unwrap()),
+                DaysMs => {
+                    let value = dyn_value!(scalar, PrimitiveScalar<days_ms>).unwrap();
+                    Value::Seq(vec![
+                        Value::Int(value.days() as i64),
+                        Value::Int(value.milliseconds() as i64),
+                    ])
+                }
+                MonthDayNano => {
+                    let value = dyn_value!(scalar, PrimitiveScalar<months_days_ns>).unwrap();
+                    Value::Seq(vec![
+                        Value::Int(value.months() as i64),
+                        Value::Int(value.days() as i64),
+                        Value::Int(value.ns()),
+                    ])
+                }
+            }
+        }
+        Utf8 => Value::String(dyn_value!(scalar, Utf8Scalar<i32>).unwrap().to_string()),
+        LargeUtf8 => Value::String(dyn_value!(scalar, Utf8Scalar<i64>).unwrap().to_string()),
+        Binary => binary_value(dyn_value!(scalar, BinaryScalar<i32>).unwrap()),
+        LargeBinary => binary_value(dyn_value!(scalar, BinaryScalar<i64>).unwrap()),
+        FixedSizeBinary => binary_value(dyn_value!(scalar, FixedSizeBinaryScalar).unwrap()),
+        List => {
+            let scalar = scalar.as_any().downcast_ref::<ListScalar<i32>>().unwrap();
+            array_value(scalar.values().as_ref())?
+        }
+        LargeList => {
+            let scalar = scalar.as_any().downcast_ref::<ListScalar<i64>>().unwrap();
+            array_value(scalar.values().as_ref())?
+        }
+        FixedSizeList => {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeListScalar>()
+                .unwrap();
+            array_value(scalar.values().unwrap().as_ref())?
+        }
+        Struct => {
+            let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+            scalar
+                .values()
+                .iter()
+                .map(|x| to_value(x.as_ref()))
+                .collect::<Result<Vec<_>>>()
+                .map(Value::Seq)?
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Serializing scalars of physical type {:?}",
+                other
+            )))
+        }
+    })
+}
+
+fn invalid(data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "The serialized value is not a valid value of data type {:?}",
+        data_type
+    ))
+}
+
+fn to_number<T: num_traits::NumCast>(value: Value, data_type: &DataType) -> Result<T> {
+    match value {
+        Value::Int(value) => num_traits::cast(value),
+        Value::UInt(value) => num_traits::cast(value),
+        Value::Float(value) => num_traits::cast(value),
+        _ => None,
+    }
+    .ok_or_else(|| invalid(data_type))
+}
+
+fn to_seq(value: Value, data_type: &DataType) -> Result<Vec<Value>> {
+    match value {
+        Value::Seq(values) => Ok(values),
+        _ => Err(invalid(data_type)),
+    }
+}
+
+fn to_bytes(value: Value, data_type: &DataType) -> Result<Vec<u8>> {
+    to_seq(value, data_type)?
+        .into_iter()
+        .map(|x| to_number::<u8>(x, data_type))
+        .collect()
+}
+
+fn to_string(value: Value, data_type: &DataType) -> Result<String> {
+    match value {
+        Value::String(value) => Ok(value),
+        _ => Err(invalid(data_type)),
+    }
+}
+
+/// Returns an [`Array`] of `data_type` with one entry per value.
+fn to_array(values: Vec<Value>, data_type: &DataType) -> Result<Arc<dyn Array>> {
+    let arrays = values
+        .into_iter()
+        .map(|value| from_value(value, data_type.clone()).map(|x| x.to_boxed_array(1)))
+        .collect::<Result<Vec<_>>>()?;
+    if arrays.is_empty() {
+        return Ok(new_empty_array(data_type.clone()).into());
+    }
+    let arrays = arrays.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+    let mut growable = make_growable(&arrays, true, arrays.len());
+    (0..arrays.len()).for_each(|i| growable.extend(i, 0, 1));
+    Ok(growable.as_arc())
+}
+
+fn from_primitive(
+    primitive: PrimitiveType,
+    value: Value,
+    data_type: DataType,
+) -> Result<Box<dyn Scalar>> {
+    macro_rules! native {
+        ($ty:ty) => {{
+            let value = to_number::<$ty>(value, &data_type)?;
+            Box::new(PrimitiveScalar::<$ty>::new(data_type, Some(value)))
+        }};
+    }
+
+    use PrimitiveType::*;
+    let scalar: Box<dyn Scalar> = match primitive {
+        Int8 => native!(i8),
+        Int16 => native!(i16),
+        Int32 => native!(i32),
+        Int64 => native!(i64),
+        Int128 => {
+            let value = match value {
+                Value::String(value) => value.parse::<i128>().ok(),
+                Value::Int(value) => Some(value as i128),
+                Value::UInt(value) => Some(value as i128),
+                _ => None,
+            }
+            .ok_or_else(|| invalid(&data_type))?;
+            Box::new(PrimitiveScalar::<i128>::new(data_type, Some(value)))
+        }
+        UInt8 => native!(u8),
+        UInt16 => native!(u16),
+        UInt32 => native!(u32),
+        UInt64 => native!(u64),
+        Float32 => native!(f32),
+        Float64 => native!(f64),
+        DaysMs => {
+            let values = to_seq(value, &data_type)?;
+            if values.len() != 2 {
+                return Err(invalid(&data_type));
+            }
+            let mut values = values.into_iter();
+            let days = to_number(values.next().unwrap(), &data_type)?;
+            let milliseconds = to_number(values.next().unwrap(), &data_type)?;
+            let value = days_ms::new(days, milliseconds);
+            Box::new(PrimitiveScalar::new(data_type, Some(value)))
+        }
+        MonthDayNano => {
+            let values = to_seq(value, &data_type)?;
+            if values.len() != 3 {
+                return Err(invalid(&data_type));
+            }
+            let mut values = values.into_iter();
+            let months = to_number(values.next().unwrap(), &data_type)?;
+            let days = to_number(values.next().unwrap(), &data_type)?;
+            let ns = to_number(values.next().unwrap(), &data_type)?;
+            let value = months_days_ns::new(months, days, ns);
+            Box::new(PrimitiveScalar::new(data_type, Some(value)))
+        }
+    };
+    Ok(scalar)
+}
+
+fn from_list<O: Offset>(value: Value, data_type: DataType) -> Result<Box<dyn Scalar>> {
+    let child = ListArray::<O>::get_child_type(&data_type);
+    let values = to_array(to_seq(value, &data_type)?, child)?;
+    Ok(Box::new(ListScalar::<O>::new(data_type, Some(values))))
+}
+
+fn from_value(value: Value, data_type: DataType) -> Result<Box<dyn Scalar>> {
+    if let Value::Null = value {
+        return Ok(new_scalar(new_null_array(data_type, 1).as_ref(), 0));
+    }
+    use PhysicalType::*;
+    let scalar: Box<dyn Scalar> = match data_type.to_physical_type() {
+        Null => return Err(invalid(&data_type)),
+        Boolean => match value {
+            Value::Bool(value) => Box::new(BooleanScalar::new(Some(value))),
+            _ => return Err(invalid(&data_type)),
+        },
+        Primitive(primitive) => return from_primitive(primitive, value, data_type),
+        Utf8 => Box::new(Utf8Scalar::<i32>::new(Some(to_string(value, &data_type)?))),
+        LargeUtf8 => Box::new(Utf8Scalar::<i64>::new(Some(to_string(value, &data_type)?))),
+        Binary => Box::new(BinaryScalar::<i32>::new(Some(to_bytes(value, &data_type)?))),
+        LargeBinary => Box::new(BinaryScalar::<i64>::new(Some(to_bytes(value, &data_type)?))),
+        FixedSizeBinary => {
+            let value = to_bytes(value, &data_type)?;
+            if value.len() != FixedSizeBinaryArray::get_size(&data_type) {
+                return Err(invalid(&data_type));
+            }
+            Box::new(FixedSizeBinaryScalar::new(data_type, Some(value)))
+        }
+        List => return from_list::<i32>(value, data_type),
+        LargeList => return from_list::<i64>(value, data_type),
+        FixedSizeList => {
+            let (field, size) = FixedSizeListArray::get_child_and_size(&data_type);
+            let values = to_array(to_seq(value, &data_type)?, field.data_type())?;
+            if values.len() != size {
+                return Err(invalid(&data_type));
+            }
+            Box::new(FixedSizeListScalar::new(data_type, Some(values)))
+        }
+        Struct => {
+            let fields = StructArray::get_fields(&data_type);
+            let values = to_seq(value, &data_type)?;
+            if values.len() != fields.len() {
+                return Err(invalid(&data_type));
+            }
+            let values = fields
+                .iter()
+                .zip(values)
+                .map(|(field, value)| from_value(value, field.data_type().clone()).map(Arc::from))
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(StructScalar::new(data_type, Some(values)))
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Deserializing scalars of physical type {:?}",
+                other
+            )))
+        }
+    };
+    Ok(scalar)
+}
+
+impl serde::Serialize for dyn Scalar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        Tagged {
+            data_type: self.data_type().clone(),
+            value: to_value(self).map_err(S::Error::custom)?,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Box<dyn Scalar> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let tagged = Tagged::deserialize(deserializer)?;
+        from_value(tagged.value, tagged.data_type).map_err(D::Error::custom)
+    }
+}
+
+macro_rules! impl_serde {
+    ($ty:ty $(, $generic:ident: $bound:ident)?) => {
+        impl$(<$generic: $bound>)? serde::Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                (self as &dyn Scalar).serialize(serializer)
+            }
+        }
+
+        impl<'de $(, $generic: $bound)?> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let scalar = Box::<dyn Scalar>::deserialize(deserializer)?;
+                scalar
+                    .as_any()
+                    .downcast_ref::<Self>()
+                    .cloned()
+                    .ok_or_else(|| {
+                        D::Error::custom(format!(
+                            "A scalar of data type {:?} can't be deserialized to {}",
+                            scalar.data_type(),
+                            std::any::type_name::<Self>()
+                        ))
+                    })
+            }
+        }
+    };
+}
+
+impl_serde!(NullScalar);
+impl_serde!(BooleanScalar);
+impl_serde!(PrimitiveScalar<T>, T: NativeType);
+impl_serde!(Utf8Scalar<O>, O: Offset);
+impl_serde!(BinaryScalar<O>, O: Offset);
+impl_serde!(FixedSizeBinaryScalar);
+impl_serde!(ListScalar<O>, O: Offset);
+impl_serde!(FixedSizeListScalar);
+impl_serde!(StructScalar);
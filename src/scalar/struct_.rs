@@ -1,8 +1,12 @@
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use crate::datatypes::DataType;
+use crate::{
+    array::{Array, StructArray},
+    datatypes::DataType,
+};
 
-use super::Scalar;
+use super::{hash_scalar, Scalar, NULL_HASH_SENTINEL};
 
 /// A single entry of a [`crate::array::StructArray`].
 #[derive(Debug, Clone)]
@@ -54,4 +58,49 @@ impl Scalar for StructScalar {
     fn data_type(&self) -> &DataType {
         &self.data_type
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        if !self.is_valid {
+            return Box::new(StructArray::new_null(self.data_type.clone(), length));
+        }
+        let values = self
+            .values
+            .iter()
+            .map(|x| x.to_boxed_array(length).into())
+            .collect();
+        Box::new(StructArray::new(self.data_type.clone(), values, None))
+    }
+}
+
+impl std::fmt::Display for StructScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.is_valid {
+            return f.write_str("null");
+        }
+        let fields = StructArray::get_fields(&self.data_type);
+        f.write_str("{")?;
+        for (index, (field, value)) in fields.iter().zip(self.values.iter()).enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}: {}", field.name, value.as_ref())?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl Hash for StructScalar {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.is_valid {
+            self.values
+                .iter()
+                .for_each(|value| hash_scalar(value.as_ref(), state))
+        } else {
+            NULL_HASH_SENTINEL.hash(state)
+        }
+    }
 }
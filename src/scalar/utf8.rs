@@ -1,9 +1,11 @@
+use std::hash::{Hash, Hasher};
+
 use crate::{array::*, datatypes::DataType};
 
-use super::Scalar;
+use super::{ord::partial_cmp_nulls_last, Scalar, NULL_HASH_SENTINEL};
 
 /// The implementation of [`Scalar`] for utf8, semantically equivalent to [`Option<String>`].
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Utf8Scalar<O: Offset> {
     value: Option<String>,
     phantom: std::marker::PhantomData<O>,
@@ -19,6 +21,23 @@ impl<O: Offset> Utf8Scalar<O> {
         }
     }
 
+    /// Returns a new [`Utf8Scalar`] that takes ownership of `value`, thereby not copying it.
+    /// # Example
+    /// ```
+    /// use arrow2::scalar::Utf8Scalar;
+    ///
+    /// let value = format!("{}-{}", "a", 1);
+    /// let scalar = Utf8Scalar::<i32>::from_string(Some(value));
+    /// assert_eq!(scalar.value(), Some("a-1"));
+    /// ```
+    #[inline]
+    pub fn from_string(value: Option<String>) -> Self {
+        Self {
+            value,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
     /// Returns the value irrespectively of the validity.
     #[inline]
     pub fn value(&self) -> Option<&str> {
@@ -52,4 +71,42 @@ impl<O: Offset> Scalar for Utf8Scalar<O> {
             &DataType::Utf8
         }
     }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        match &self.value {
+            Some(value) => Box::new(Utf8Array::<O>::from_trusted_len_values_iter(
+                std::iter::repeat(value).take(length),
+            )),
+            None => Box::new(Utf8Array::<O>::new_null(self.data_type().clone(), length)),
+        }
+    }
+}
+
+impl<O: Offset> Hash for Utf8Scalar<O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.value() {
+            Some(value) => value.hash(state),
+            None => NULL_HASH_SENTINEL.hash(state),
+        }
+    }
+}
+
+impl<O: Offset> PartialOrd for Utf8Scalar<O> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        partial_cmp_nulls_last(self.value(), other.value())
+    }
+}
+
+impl<O: Offset> std::fmt::Display for Utf8Scalar<O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value() {
+            Some(value) => write!(f, "\"{}\"", value),
+            None => f.write_str("null"),
+        }
+    }
 }
@@ -155,3 +155,28 @@ fn extend_trusted_len() {
         Some(&Bitmap::from_u8_slice(&[0b00001011], 4))
     );
 }
+
+#[test]
+fn extend_from_array() {
+    let mut a = MutableBinaryArray::<i32>::from_iter([Some(b"a"), None]);
+    let other = BinaryArray::<i32>::from([None, Some(b"b")]);
+    a.extend_from_array(&other).unwrap();
+    let a: BinaryArray<i32> = a.into();
+    assert_eq!(
+        a,
+        BinaryArray::<i32>::from([Some(b"a"), None, None, Some(b"b")])
+    );
+}
+
+#[test]
+fn pop_then_push() {
+    let mut a = MutableBinaryArray::<i32>::from_iter([Some(b"a".as_ref()), Some(b"bc"), None]);
+    assert_eq!(a.pop(), None);
+    assert_eq!(a.pop(), Some(b"bc".to_vec()));
+    a.push(Some(b"d"));
+    let a: BinaryArray<i32> = a.into();
+    assert_eq!(
+        a,
+        BinaryArray::<i32>::from([Some(b"a".as_ref()), Some(b"d")])
+    );
+}
@@ -39,6 +39,29 @@ fn basics() {
     assert!(!array.value(1));
 }
 
+#[test]
+fn counts() {
+    // the values under the nulls are `true` and `false` and must not be counted
+    let array = BooleanArray::new(
+        DataType::Boolean,
+        Bitmap::from([true, true, false, false, true, true]),
+        Some(Bitmap::from([true, false, true, false, true, true])),
+    );
+    assert_eq!(array.count_true(), 3);
+    assert_eq!(array.count_false(), 1);
+    assert_eq!(array.count_null(), 2);
+
+    let array = array.slice(1, 3);
+    assert_eq!(array.count_true(), 0);
+    assert_eq!(array.count_false(), 1);
+    assert_eq!(array.count_null(), 2);
+
+    let array = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(array.count_true(), 2);
+    assert_eq!(array.count_false(), 1);
+    assert_eq!(array.count_null(), 0);
+}
+
 #[test]
 fn with_validity() {
     let bitmap = Bitmap::from([true, false, true]);
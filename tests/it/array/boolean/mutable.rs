@@ -142,3 +142,14 @@ fn extend_trusted_len_values() {
     );
     assert_eq!(a.values(), &MutableBitmap::from([false, true, false]));
 }
+
+#[test]
+fn extend_from_array() {
+    let mut a = MutableBooleanArray::from_slice([true, false]);
+    let other = arrow2::array::BooleanArray::from([None, Some(true)]);
+    a.extend_from_array(&other).unwrap();
+    assert_eq!(
+        a,
+        MutableBooleanArray::from([Some(true), Some(false), None, Some(true)])
+    );
+}
@@ -154,3 +154,27 @@ fn shrink_to_fit_and_capacity() {
     array.shrink_to_fit();
     assert_eq!(array.capacity(), 1);
 }
+
+#[test]
+fn reserve() {
+    let mut a = MutableFixedSizeBinaryArray::new(2);
+    let array: &mut dyn MutableArray = &mut a;
+    array.reserve(10);
+    assert!(a.values().capacity() >= 20);
+}
+
+#[test]
+fn extend_from_array() {
+    let mut a = MutableFixedSizeBinaryArray::try_from_iter(vec![Some(b"ab")], 2).unwrap();
+    let other = FixedSizeBinaryArray::from_iter(vec![None, Some(b"cd")], 2);
+    a.extend_from_array(&other).unwrap();
+    let a: FixedSizeBinaryArray = a.into();
+    assert_eq!(
+        a,
+        FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), None, Some(b"cd")], 2)
+    );
+
+    let other = FixedSizeBinaryArray::from_iter(vec![Some(b"abc")], 3);
+    let mut a = MutableFixedSizeBinaryArray::new(2);
+    assert!(a.extend_from_array(&other).is_err());
+}
@@ -63,3 +63,42 @@ fn new_with_field() {
     let expected = Int32Array::from(vec![None, None, None]);
     assert_eq!(a, &expected)
 }
+
+#[test]
+fn try_new() {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+
+    let mut list =
+        MutableFixedSizeListArray::try_new(data_type.clone(), MutablePrimitiveArray::<i32>::new())
+            .unwrap();
+    list.mut_values().extend_from_slice(&[1, 2]);
+    list.try_push_valid().unwrap();
+    list.push_null();
+    let list: FixedSizeListArray = list.into();
+
+    assert_eq!(list.data_type(), &data_type);
+    assert_eq!(list.len(), 2);
+    assert!(list.is_null(1));
+    assert_eq!(
+        list.values().as_ref(),
+        &Int32Array::from([Some(1), Some(2), None, None]) as &dyn Array
+    );
+
+    // the child's data type must match
+    assert!(
+        MutableFixedSizeListArray::try_new(data_type, MutablePrimitiveArray::<i64>::new()).is_err()
+    );
+    // the data type must be a fixed size list
+    assert!(MutableFixedSizeListArray::try_new(
+        DataType::Int32,
+        MutablePrimitiveArray::<i32>::new()
+    )
+    .is_err());
+}
+
+#[test]
+fn reserve() {
+    let mut list = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), 3);
+    MutableArray::reserve(&mut list, 10);
+    assert!(list.values().capacity() >= 30);
+}
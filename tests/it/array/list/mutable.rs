@@ -50,3 +50,10 @@ fn push() {
     assert_eq!(array.offsets().as_ref(), [0, 3]);
     assert_eq!(array.validity(), None);
 }
+
+#[test]
+fn reserve() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    MutableArray::reserve(&mut array, 10);
+    assert!(array.offsets().capacity() >= 11);
+}
@@ -73,6 +73,15 @@ fn empty() {
     assert!(a);
 }
 
+#[test]
+fn is_empty() {
+    let array = new_empty_array(DataType::Int32);
+    assert!(array.is_empty());
+    let array: std::sync::Arc<dyn Array> = new_null_array(DataType::Int32, 1).into();
+    assert!(!array.is_empty());
+    assert!(array.slice(0, 0).is_empty());
+}
+
 #[test]
 fn test_clone() {
     let datatypes = vec![
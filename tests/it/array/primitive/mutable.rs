@@ -291,3 +291,19 @@ fn wrong_data_type() {
     let values = vec![1u8];
     MutablePrimitiveArray::from_data(DataType::Utf8, values, None);
 }
+
+#[test]
+fn extend_from_array() {
+    let mut a = MutablePrimitiveArray::<i32>::from([Some(1), Some(2)]);
+    let other = Int32Array::from([None, Some(3)]);
+    a.extend_from_array(&other).unwrap();
+    let a: Int32Array = a.into();
+    assert_eq!(a, Int32Array::from([Some(1), Some(2), None, Some(3)]));
+}
+
+#[test]
+fn extend_from_array_wrong_type() {
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    let other = Int32Array::from([Some(1)]).to(DataType::Date32);
+    assert!(a.extend_from_array(&other).is_err());
+}
@@ -1,4 +1,5 @@
 mod iterator;
+mod mutable;
 
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
@@ -25,3 +26,30 @@ fn debug() {
         "StructArray[{b: false, c: 42}, {b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+#[test]
+fn try_from_columns() {
+    use std::sync::Arc;
+    let int = Arc::new(Int32Array::from(&[Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let utf8 = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "c"])) as Arc<dyn Array>;
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ];
+
+    let validity = Some(Bitmap::from([true, false, true]));
+    let array =
+        StructArray::try_from_columns(fields.clone(), vec![int.clone(), utf8.clone()], validity)
+            .unwrap();
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(array.fields(), fields.as_slice());
+    assert_eq!(array.values(), &[int.clone(), utf8]);
+
+    // columns of different lengths
+    let short = Arc::new(Utf8Array::<i32>::from_slice(&["a"])) as Arc<dyn Array>;
+    assert!(StructArray::try_from_columns(fields.clone(), vec![int.clone(), short], None).is_err());
+
+    // fewer columns than fields
+    assert!(StructArray::try_from_columns(fields, vec![int], None).is_err());
+}
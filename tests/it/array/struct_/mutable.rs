@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::{
+    BooleanScalar, FixedSizeBinaryScalar, ListScalar, PrimitiveScalar, Scalar, StructScalar,
+    Utf8Scalar,
+};
+
+fn data_type() -> DataType {
+    DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ])
+}
+
+fn new_array() -> MutableStructArray {
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    MutableStructArray::new(data_type(), values, None)
+}
+
+#[test]
+fn push() {
+    let mut array = new_array();
+    array
+        .push_value(vec![
+            Box::new(PrimitiveScalar::from(Some(1i32))) as Box<dyn Scalar>,
+            Box::new(Utf8Scalar::<i32>::new(Some("a"))),
+        ])
+        .unwrap();
+    array.push_null();
+    array
+        .push_value(vec![
+            Box::new(PrimitiveScalar::<i32>::from(None)) as Box<dyn Scalar>,
+            Box::new(Utf8Scalar::<i32>::new(Some("c"))),
+        ])
+        .unwrap();
+    assert_eq!(array.len(), 3);
+
+    let array: StructArray = array.into();
+
+    let expected = StructArray::new(
+        data_type(),
+        vec![
+            Arc::new(Int32Array::from([Some(1), None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("c")])),
+        ],
+        Some([true, false, true].into()),
+    );
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn push_nested() {
+    let inner = data_type();
+    let data_type = DataType::Struct(vec![
+        Field::new("s", inner.clone(), true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+    let values: Vec<Box<dyn MutableArray>> =
+        vec![Box::new(new_array()), Box::new(MutableBooleanArray::new())];
+    let mut array = MutableStructArray::new(data_type, values, None);
+
+    let valid = StructScalar::new(
+        inner.clone(),
+        Some(vec![
+            Arc::new(PrimitiveScalar::from(Some(1i32))) as Arc<dyn Scalar>,
+            Arc::new(Utf8Scalar::<i32>::new(Some("a"))),
+        ]),
+    );
+    array
+        .push_value(vec![
+            Box::new(valid) as Box<dyn Scalar>,
+            Box::new(BooleanScalar::from(Some(true))),
+        ])
+        .unwrap();
+    array
+        .push_value(vec![
+            Box::new(StructScalar::new(inner, None)) as Box<dyn Scalar>,
+            Box::new(BooleanScalar::from(None)),
+        ])
+        .unwrap();
+
+    let array = array.as_box();
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.validity(), None);
+    let inner = array.values()[0]
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert!(inner.is_valid(0));
+    assert!(inner.is_null(1));
+}
+
+#[test]
+fn push_invalid() {
+    let mut array = new_array();
+
+    // wrong number of values
+    assert!(array
+        .push_value(vec![
+            Box::new(PrimitiveScalar::from(Some(1i32))) as Box<dyn Scalar>
+        ])
+        .is_err());
+
+    // wrong data type
+    assert!(array
+        .push_value(vec![
+            Box::new(PrimitiveScalar::from(Some(1i64))) as Box<dyn Scalar>,
+            Box::new(Utf8Scalar::<i32>::new(Some("a"))),
+        ])
+        .is_err());
+
+    // children are left untouched
+    assert_eq!(array.len(), 0);
+    assert!(array.values().iter().all(|x| x.is_empty()));
+}
+
+#[test]
+fn push_not_yet_implemented() {
+    let list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    let list_type = list.data_type().clone();
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", list_type.clone(), true),
+    ]);
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(list),
+    ];
+    let mut array = MutableStructArray::new(data_type, values, None);
+
+    let list = Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>;
+    assert!(array
+        .push_value(vec![
+            Box::new(PrimitiveScalar::from(Some(1i32))) as Box<dyn Scalar>,
+            Box::new(ListScalar::<i32>::new(list_type, Some(list))),
+        ])
+        .is_err());
+
+    // the first child is not pushed to either
+    assert!(array.values().iter().all(|x| x.is_empty()));
+}
+
+#[test]
+fn push_invalid_fixed_size_binary() {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::FixedSizeBinary(2), true),
+    ]);
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableFixedSizeBinaryArray::new(2)),
+    ];
+    let mut array = MutableStructArray::new(data_type, values, None);
+
+    assert!(array
+        .push_value(vec![
+            Box::new(PrimitiveScalar::from(Some(1i32))) as Box<dyn Scalar>,
+            Box::new(FixedSizeBinaryScalar::new(
+                DataType::FixedSizeBinary(2),
+                Some(vec![1u8, 2, 3])
+            )),
+        ])
+        .is_err());
+
+    assert!(array.values().iter().all(|x| x.is_empty()));
+}
+
+#[test]
+fn try_new_invalid() {
+    let values: Vec<Box<dyn MutableArray>> = vec![Box::new(MutablePrimitiveArray::<i32>::new())];
+    assert!(MutableStructArray::try_new(data_type(), values, None).is_err());
+
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::from([Some(1)])),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    assert!(MutableStructArray::try_new(data_type(), values, None).is_err());
+}
+
+#[test]
+fn reserve() {
+    let mut array = new_array();
+    MutableArray::reserve(&mut array, 10);
+    let a = array.values()[0]
+        .as_any()
+        .downcast_ref::<MutablePrimitiveArray<i32>>()
+        .unwrap();
+    assert!(a.capacity() >= 10);
+}
@@ -236,3 +236,115 @@ fn iter_dense_slice() -> Result<()> {
 
     Ok(())
 }
+
+fn mutable_fields() -> Vec<Box<dyn MutableArray>> {
+    vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ]
+}
+
+#[test]
+fn mutable_sparse() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+
+    let mut array = MutableUnionArray::try_new(data_type.clone(), mutable_fields())?;
+    array.push(0, Box::new(PrimitiveScalar::from(Some(1i32))))?;
+    array.push(1, Box::new(Utf8Scalar::<i32>::new(Some("a"))))?;
+    array.push(0, Box::new(PrimitiveScalar::<i32>::from(None)))?;
+    let array: UnionArray = array.into();
+
+    let expected = UnionArray::new(
+        data_type,
+        vec![0, 1, 0].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, Some("a"), None])),
+        ],
+        None,
+    );
+    assert_eq!(array, expected);
+    Ok(())
+}
+
+#[test]
+fn mutable_dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+
+    let mut array = MutableUnionArray::try_new(data_type.clone(), mutable_fields())?;
+    array.push(5, Box::new(PrimitiveScalar::from(Some(1i32))))?;
+    array.push(7, Box::new(Utf8Scalar::<i32>::new(Some("a"))))?;
+    array.push(5, Box::new(PrimitiveScalar::from(Some(2i32))))?;
+    array.push_null();
+    assert_eq!(array.offsets(), Some([0, 0, 1, 2].as_ref()));
+    let array: UnionArray = array.into();
+
+    let expected = UnionArray::new(
+        data_type,
+        vec![5, 7, 5, 5].into(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), Some(2), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a")])),
+        ],
+        Some(vec![0, 0, 1, 2].into()),
+    );
+    assert_eq!(array, expected);
+    Ok(())
+}
+
+#[test]
+fn mutable_invalid_push() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+
+    let mut array = MutableUnionArray::try_new(data_type, mutable_fields())?;
+    // out of range type id
+    assert!(array
+        .push(2, Box::new(PrimitiveScalar::from(Some(1i32))))
+        .is_err());
+    // wrong data type
+    assert!(array
+        .push(1, Box::new(PrimitiveScalar::from(Some(1i32))))
+        .is_err());
+    assert_eq!(array.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn mutable_push_null_without_fields() -> Result<()> {
+    let data_type = DataType::Union(vec![], None, UnionMode::Dense);
+
+    let mut array = MutableUnionArray::try_new(data_type, vec![])?;
+    assert!(array.try_push_null().is_err());
+    assert_eq!(array.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn mutable_reserve_sparse() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+
+    let mut array = MutableUnionArray::try_new(data_type, mutable_fields())?;
+    MutableArray::reserve(&mut array, 10);
+    let a = array.fields()[0]
+        .as_any()
+        .downcast_ref::<MutablePrimitiveArray<i32>>()
+        .unwrap();
+    assert!(a.capacity() >= 10);
+    Ok(())
+}
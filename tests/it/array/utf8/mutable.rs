@@ -141,3 +141,29 @@ fn test_extend_values() {
     assert_eq!(array.offsets().as_slice(), &[0, 2, 7, 12, 17]);
     assert_eq!(array.validity(), None,);
 }
+
+#[test]
+fn extend_from_array() {
+    let mut a = MutableUtf8Array::<i32>::from_iter([Some("a"), None]);
+    let other = Utf8Array::<i32>::from([Some("b"), None, Some("c")]);
+    a.extend_from_array(&other).unwrap();
+    let a: Utf8Array<i32> = a.into();
+    assert_eq!(
+        a,
+        Utf8Array::<i32>::from([Some("a"), None, Some("b"), None, Some("c")])
+    );
+
+    let other = Utf8Array::<i64>::from([Some("b")]);
+    let mut a = MutableUtf8Array::<i32>::new();
+    assert!(a.extend_from_array(&other).is_err());
+}
+
+#[test]
+fn pop_then_push() {
+    let mut a = MutableUtf8Array::<i32>::from_iter([Some("a"), Some("bc"), None]);
+    assert_eq!(a.pop(), None);
+    assert_eq!(a.pop(), Some("bc".to_string()));
+    a.push(Some("d"));
+    let a: Utf8Array<i32> = a.into();
+    assert_eq!(a, Utf8Array::<i32>::from([Some("a"), Some("d")]));
+}
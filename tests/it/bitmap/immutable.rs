@@ -39,3 +39,39 @@ fn debug() {
 
     assert_eq!(format!("{:?}", b), "[0b111110__, 0b_______1]");
 }
+
+#[test]
+fn set_and_unset_bits() {
+    let b = Bitmap::from([
+        true, false, true, true, false, true, true, true, false, true,
+    ]);
+    assert_eq!(b.set_bits(), 7);
+    assert_eq!(b.unset_bits(), 3);
+    assert_eq!(b.set_bits() + b.unset_bits(), b.len());
+
+    let b = b.slice(1, 8);
+    assert_eq!(b.set_bits(), 5);
+    assert_eq!(b.unset_bits(), b.null_count());
+    assert_eq!(b.set_bits() + b.unset_bits(), b.len());
+
+    let b = Bitmap::new();
+    assert_eq!(b.set_bits() + b.unset_bits(), 0);
+}
+
+#[test]
+fn chunks_sliced() {
+    let b = (0..150)
+        .map(|i| i % 3 == 0 || i % 7 == 0)
+        .collect::<Bitmap>();
+    let b = b.slice(3, 140);
+
+    let mut chunks = b.chunks::<u64>();
+    let mut bits = vec![];
+    for chunk in chunks.by_ref() {
+        bits.extend((0..64).map(|i| (chunk >> i) & 1 == 1));
+    }
+    let remainder = chunks.remainder();
+    bits.extend((0..chunks.remainder_len()).map(|i| (remainder >> i) & 1 == 1));
+
+    assert_eq!(bits, b.iter().collect::<Vec<_>>());
+}
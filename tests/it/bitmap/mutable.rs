@@ -27,6 +27,18 @@ fn trusted_len_small() {
     assert_eq!(bitmap.as_slice().0[0], 0b01111111);
 }
 
+#[test]
+fn trusted_len_equals_push() {
+    let data = (0..17).map(|i| i % 3 != 0).collect::<Vec<_>>();
+    let bitmap = MutableBitmap::from_trusted_len_iter(data.iter().copied());
+
+    let mut expected = MutableBitmap::new();
+    data.into_iter().for_each(|x| expected.push(x));
+
+    assert_eq!(bitmap.len(), 17);
+    assert_eq!(bitmap, expected);
+}
+
 #[test]
 fn push() {
     let mut bitmap = MutableBitmap::new();
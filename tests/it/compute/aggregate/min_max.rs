@@ -134,6 +134,36 @@ fn test_string_min_max_1() {
     assert_eq!(Some("b"), max_string(&a));
 }
 
+#[test]
+fn test_string_min_max_unicode() {
+    // utf8 byte order is the order of code points, not of the number of bytes per character
+    let a = Utf8Array::<i64>::from(&[
+        Some("é"),
+        Some("z"),
+        Some("日本"),
+        None,
+        Some("😀"),
+        Some("ä"),
+    ]);
+    assert_eq!(Some("z"), min_string(&a));
+    assert_eq!(Some("😀"), max_string(&a));
+
+    let a = BinaryArray::<i64>::from(&[Some("é".as_bytes()), Some("日本".as_bytes()), None]);
+    assert_eq!(Some("é".as_bytes()), min_binary(&a));
+    assert_eq!(Some("日本".as_bytes()), max_binary(&a));
+}
+
+#[test]
+fn test_string_min_max_empty() {
+    let a = Utf8Array::<i32>::new_empty(DataType::Utf8);
+    assert_eq!(None, min_string(&a));
+    assert_eq!(None, max_string(&a));
+
+    let a = BinaryArray::<i32>::new_empty(DataType::Binary);
+    assert_eq!(None, min_binary(&a));
+    assert_eq!(None, max_binary(&a));
+}
+
 #[test]
 fn test_boolean_min_max_empty() {
     let a = BooleanArray::new_empty(DataType::Boolean);
@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::aggregate::{sum, sum_primitive};
+use arrow2::compute::aggregate::{mean_primitive, sum, sum_primitive};
 use arrow2::compute::arithmetics;
 use arrow2::datatypes::DataType;
 use arrow2::scalar::{PrimitiveScalar, Scalar};
@@ -52,3 +52,21 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+#[test]
+fn test_primitive_array_mean() {
+    let a = Int32Array::from(&[None, Some(2), Some(3), None, Some(5)]);
+    assert_eq!(Some(10.0 / 3.0), mean_primitive(&a));
+
+    let a = Float32Array::from_slice(&[1.5, 2.5]);
+    assert_eq!(Some(2.0), mean_primitive(&a));
+}
+
+#[test]
+fn test_primitive_array_mean_all_nulls() {
+    let a = Int64Array::from(&[None, None]);
+    assert_eq!(None, mean_primitive(&a));
+
+    let a = Int64Array::from_slice(&[]);
+    assert_eq!(None, mean_primitive(&a));
+}
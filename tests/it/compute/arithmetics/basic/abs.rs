@@ -0,0 +1,35 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+
+#[test]
+fn test_abs() {
+    let a = Int32Array::from(&[Some(-1i32), None, Some(0), Some(7), Some(i32::MAX)]);
+    let actual = abs(&a);
+    let expected = Int32Array::from(&[Some(1i32), None, Some(0), Some(7), Some(i32::MAX)]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_abs_float() {
+    let a = Float64Array::from(&[Some(-1.5f64), None, Some(f64::NEG_INFINITY)]);
+    let actual = abs(&a);
+    let expected = Float64Array::from(&[Some(1.5f64), None, Some(f64::INFINITY)]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_abs_min_overflow() {
+    let a = Int32Array::from(&[Some(i32::MIN)]);
+    let _ = abs(&a);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn test_abs_min_wraps() {
+    let a = Int32Array::from(&[Some(i32::MIN)]);
+    let actual = abs(&a);
+    let expected = Int32Array::from(&[Some(i32::MIN)]);
+    assert_eq!(expected, actual);
+}
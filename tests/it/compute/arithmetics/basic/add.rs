@@ -72,6 +72,15 @@ fn test_add_saturating() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_add_saturating_u8() {
+    let a = UInt8Array::from_slice(&[254, 255, 1]);
+    let b = UInt8Array::from_slice(&[1, 1, 255]);
+    let result = saturating_add(&a, &b);
+    let expected = UInt8Array::from_slice(&[255, 255, 255]);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_add_overflowing() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
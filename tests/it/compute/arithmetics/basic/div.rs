@@ -1,6 +1,7 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::basic::*;
 use arrow2::compute::arithmetics::{ArrayCheckedDiv, ArrayDiv};
+use arrow2::error::ArrowError;
 
 #[test]
 #[should_panic]
@@ -23,6 +24,33 @@ fn test_div() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_try_div() {
+    let a = Int32Array::from(&[Some(10), None, Some(6), Some(3)]);
+    // the zero in a null slot is ignored
+    let b = Int32Array::from(&[Some(5), Some(2), None, Some(3)]);
+    let b = b.with_validity(Some([true, true, false, true].into()));
+    let result = try_div(&a, &b).unwrap();
+    let expected = Int32Array::from(&[Some(2), None, None, Some(1)]);
+    assert_eq!(result, expected);
+
+    let b = Int32Array::from(&[Some(5), Some(0), Some(1), Some(1)]);
+    assert!(matches!(
+        try_div(&a, &b),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    let a = Int32Array::from_slice(&[5, 6]);
+    let b = Int32Array::from_slice(&[5]);
+    assert!(try_div(&a, &b).is_err());
+
+    // floats do not error on division by zero
+    let a = Float64Array::from_slice(&[1.0]);
+    let b = Float64Array::from_slice(&[0.0]);
+    let result = try_div(&a, &b).unwrap();
+    assert_eq!(result, Float64Array::from_slice(&[f64::INFINITY]));
+}
+
 #[test]
 #[should_panic]
 fn test_div_panic() {
@@ -50,6 +78,33 @@ fn test_div_checked() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_divide_integers() {
+    let a = Int32Array::from(&[Some(6), None, Some(3), Some(7)]);
+    let b = Int32Array::from(&[Some(3), Some(0), Some(0), Some(2)]);
+    let result = divide(&a, &b);
+    let expected = Int32Array::from(&[Some(2), None, None, Some(3)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_divide_floats() {
+    let a = Float64Array::from(&[Some(1.0), Some(-1.0), Some(6.0), None]);
+    let b = Float64Array::from(&[Some(0.0), Some(0.0), Some(3.0), Some(0.0)]);
+    let result = divide(&a, &b);
+    let expected = Float64Array::from(&[
+        Some(f64::INFINITY),
+        Some(f64::NEG_INFINITY),
+        Some(2.0),
+        None,
+    ]);
+    assert_eq!(result, expected);
+
+    let a = Float32Array::from_slice(&[0.0]);
+    let b = Float32Array::from_slice(&[0.0]);
+    assert!(divide(&a, &b).value(0).is_nan());
+}
+
 #[test]
 fn test_div_scalar() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
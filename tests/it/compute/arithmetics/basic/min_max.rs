@@ -0,0 +1,44 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+
+#[test]
+fn test_min_max() {
+    let a = Int32Array::from(&[Some(1), Some(5), None, Some(-3)]);
+    let b = Int32Array::from(&[Some(2), Some(3), Some(4), Some(-3)]);
+
+    let result = min(&a, &b);
+    let expected = Int32Array::from(&[Some(1), Some(3), None, Some(-3)]);
+    assert_eq!(result, expected);
+
+    let result = max(&a, &b);
+    let expected = Int32Array::from(&[Some(2), Some(5), None, Some(-3)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[should_panic]
+fn test_min_mismatched_length() {
+    let a = Int32Array::from_slice(&[5, 6]);
+    let b = Int32Array::from_slice(&[5]);
+    min(&a, &b);
+}
+
+#[test]
+fn test_min_max_nan() {
+    let a = Float64Array::from_slice(&[f64::NAN, 1.0, f64::NAN]);
+    let b = Float64Array::from_slice(&[1.0, f64::NAN, f64::NAN]);
+
+    let result = min(&a, &b);
+    assert!(result.values().iter().all(|x| x.is_nan()));
+
+    let result = max(&a, &b);
+    assert!(result.values().iter().all(|x| x.is_nan()));
+
+    let a = Float32Array::from_slice(&[1.0, -0.5]);
+    let b = Float32Array::from_slice(&[f32::INFINITY, -1.0]);
+    assert_eq!(min(&a, &b), Float32Array::from_slice(&[1.0, -1.0]));
+    assert_eq!(
+        max(&a, &b),
+        Float32Array::from_slice(&[f32::INFINITY, -0.5])
+    );
+}
@@ -1,5 +1,7 @@
+mod abs;
 mod add;
 mod div;
+mod min_max;
 mod mul;
 mod pow;
 mod rem;
@@ -72,6 +72,15 @@ fn test_mul_saturating() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_mul_saturating_u8() {
+    let a = UInt8Array::from_slice(&[128, 255, 15]);
+    let b = UInt8Array::from_slice(&[2, 255, 17]);
+    let result = saturating_mul(&a, &b);
+    let expected = UInt8Array::from_slice(&[255, 255, 255]);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_mul_overflowing() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
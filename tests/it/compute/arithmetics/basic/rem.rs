@@ -50,6 +50,22 @@ fn test_rem_checked() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_rem_negative() {
+    // the result has the sign of the dividend, as Rust's `%`
+    let a = Int32Array::from_slice(&[7, -7, 7, -7]);
+    let b = Int32Array::from_slice(&[3, 3, -3, -3]);
+    let expected = Int32Array::from_slice(&[1, -1, 1, -1]);
+    assert_eq!(rem(&a, &b), expected);
+    assert_eq!(checked_rem(&a, &b), expected);
+
+    let a = Int32Array::from(&[Some(-7), Some(-7), None]);
+    let b = Int32Array::from(&[Some(0), Some(-3), Some(0)]);
+    let result = checked_rem(&a, &b);
+    let expected = Int32Array::from(&[None, Some(-1), None]);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_rem_scalar() {
     let a = Int32Array::from(&[None, Some(6), None, Some(5)]);
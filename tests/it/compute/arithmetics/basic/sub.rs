@@ -72,6 +72,15 @@ fn test_sub_saturating() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_sub_saturating_u8() {
+    let a = UInt8Array::from_slice(&[1, 0, 255]);
+    let b = UInt8Array::from_slice(&[1, 1, 255]);
+    let result = saturating_sub(&a, &b);
+    let expected = UInt8Array::from_slice(&[0, 0, 0]);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_sub_overflowing() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
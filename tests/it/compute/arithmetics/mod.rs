@@ -1,12 +1,13 @@
 mod basic;
 mod decimal;
+mod scalar;
 mod time;
 
 use arrow2::array::*;
 use arrow2::compute::arithmetics::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::{IntervalUnit, TimeUnit};
-use arrow2::scalar::PrimitiveScalar;
+use arrow2::scalar::{NullScalar, PrimitiveScalar};
 
 #[test]
 fn test_add() {
@@ -26,6 +27,19 @@ fn test_add_scalar() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_add_scalar_null() {
+    let a = Int32Array::from(&[None, Some(6)]);
+    let expected = Int32Array::new_null(Int32, 2);
+
+    let b = PrimitiveScalar::<i32>::from(None);
+    assert_eq!(expected, add_scalar(&a, &b).as_ref());
+
+    let b = NullScalar::new_typed(Int32);
+    assert_eq!(expected, add_scalar(&a, &b).as_ref());
+    assert_eq!(expected, mul_scalar(&a, &b).as_ref());
+}
+
 #[test]
 fn consistency() {
     let datatypes = vec![
@@ -0,0 +1,42 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::scalar::*;
+use arrow2::scalar::PrimitiveScalar;
+
+#[test]
+fn with_valid_scalar() {
+    let a = Int32Array::from(&[None, Some(6), Some(-2)]);
+    let rhs = PrimitiveScalar::from(Some(2i32));
+
+    let expected = Int32Array::from(&[None, Some(8), Some(0)]);
+    assert_eq!(add_primitive_scalar(&a, &rhs), expected);
+    let expected = Int32Array::from(&[None, Some(4), Some(-4)]);
+    assert_eq!(sub_primitive_scalar(&a, &rhs), expected);
+    let expected = Int32Array::from(&[None, Some(12), Some(-4)]);
+    assert_eq!(mul_primitive_scalar(&a, &rhs), expected);
+    let expected = Int32Array::from(&[None, Some(3), Some(-1)]);
+    assert_eq!(div_primitive_scalar(&a, &rhs), expected);
+}
+
+#[test]
+fn with_null_scalar() {
+    let a = Int32Array::from(&[None, Some(6), Some(-2)]);
+    let rhs = PrimitiveScalar::<i32>::from(None);
+
+    let expected = Int32Array::new_null(a.data_type().clone(), 3);
+    assert_eq!(add_primitive_scalar(&a, &rhs), expected);
+    assert_eq!(sub_primitive_scalar(&a, &rhs), expected);
+    assert_eq!(mul_primitive_scalar(&a, &rhs), expected);
+    assert_eq!(div_primitive_scalar(&a, &rhs), expected);
+}
+
+#[test]
+fn overflow() {
+    let a = Int8Array::from(&[Some(i8::MAX), None, Some(i8::MIN)]);
+
+    let result = add_primitive_scalar(&a, &PrimitiveScalar::from(Some(1i8)));
+    assert_eq!(result, Int8Array::from(&[Some(i8::MIN), None, Some(-127)]));
+    let result = sub_primitive_scalar(&a, &PrimitiveScalar::from(Some(1i8)));
+    assert_eq!(result, Int8Array::from(&[Some(126), None, Some(i8::MAX)]));
+    let result = mul_primitive_scalar(&a, &PrimitiveScalar::from(Some(2i8)));
+    assert_eq!(result, Int8Array::from(&[Some(-2), None, Some(0)]));
+}
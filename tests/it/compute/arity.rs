@@ -0,0 +1,116 @@
+use arrow2::array::*;
+use arrow2::compute::arity::{binary, try_binary, try_binary_or_null, unary_in_place};
+use arrow2::datatypes::DataType;
+use arrow2::error::ArrowError;
+
+#[test]
+fn unary_in_place_owned() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]);
+    let ptr = array.values().as_ptr();
+
+    let result = unary_in_place(array, |x| x * 2);
+    // the values were not shared and were re-used
+    assert_eq!(result.values().as_ptr(), ptr);
+    assert_eq!(result, Int32Array::from(&[Some(2), None, Some(6)]));
+}
+
+#[test]
+fn unary_in_place_shared() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]);
+    let shared = array.clone();
+
+    let result = unary_in_place(array, |x| x * 2);
+    assert_ne!(result.values().as_ptr(), shared.values().as_ptr());
+    assert_eq!(result, Int32Array::from(&[Some(2), None, Some(6)]));
+    // the shared array is unchanged
+    assert_eq!(shared, Int32Array::from(&[Some(1), None, Some(3)]));
+}
+
+#[test]
+fn unary_in_place_sliced() {
+    let array = Int32Array::from_slice(&[1, 2, 3, 4]).slice(1, 2);
+
+    let result = unary_in_place(array, |x| x + 1);
+    assert_eq!(result, Int32Array::from_slice(&[3, 4]));
+}
+
+#[test]
+fn unary_in_place_sliced_from_start() {
+    // the parent is dropped, so the buffer owns the whole vector
+    let array = Int32Array::from_slice(&[1, 2, 3, 4]).slice(0, 2);
+
+    let result = unary_in_place(array, |x| x + 1);
+    assert_eq!(result, Int32Array::from_slice(&[2, 3]));
+
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]).slice(0, 2);
+
+    let result = unary_in_place(array, |x| x + 1);
+    assert_eq!(result, Int32Array::from(&[Some(2), None]));
+}
+
+#[test]
+fn binary_nulls() {
+    let a = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+    let b = Int32Array::from(&[Some(10), Some(20), None, Some(40)]);
+
+    let result = binary(&a, &b, DataType::Int32, |x, y| x + y);
+    assert_eq!(result, Int32Array::from(&[Some(11), None, None, Some(44)]));
+}
+
+#[test]
+fn binary_output_type() {
+    let a = Int32Array::from(&[Some(i32::MAX), None]);
+    let b = Int32Array::from_slice(&[1, 2]);
+
+    let result = binary(&a, &b, DataType::Int64, |x, y| x as i64 + y as i64);
+    assert_eq!(result, Int64Array::from(&[Some(i32::MAX as i64 + 1), None]));
+}
+
+#[test]
+#[should_panic]
+fn binary_different_lengths() {
+    let a = Int32Array::from_slice(&[1, 2]);
+    let b = Int32Array::from_slice(&[1]);
+
+    binary(&a, &b, DataType::Int32, |x, y| x + y);
+}
+
+#[test]
+fn try_binary_different_lengths() {
+    let a = Int32Array::from_slice(&[1, 2]);
+    let b = Int32Array::from_slice(&[1]);
+
+    let result = try_binary(&a, &b, DataType::Int32, |x, y| Ok(x + y));
+    assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+}
+
+fn checked_div(x: i32, y: i32) -> arrow2::error::Result<i32> {
+    x.checked_div(y)
+        .ok_or_else(|| ArrowError::InvalidArgumentError("division by zero".to_string()))
+}
+
+#[test]
+fn try_binary_or_null_div() {
+    let a = Int32Array::from(&[Some(10), Some(10), None, Some(9)]);
+    let b = Int32Array::from(&[Some(2), Some(0), Some(0), Some(3)]);
+
+    let result = try_binary_or_null(&a, &b, DataType::Int32, checked_div).unwrap();
+    assert_eq!(result, Int32Array::from(&[Some(5), None, None, Some(3)]));
+}
+
+#[test]
+fn try_binary_div_errors() {
+    let a = Int32Array::from_slice(&[10, 10]);
+    let b = Int32Array::from_slice(&[2, 0]);
+
+    let result = try_binary(&a, &b, DataType::Int32, checked_div);
+    assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+}
+
+#[test]
+fn try_binary_or_null_different_lengths() {
+    let a = Int32Array::from_slice(&[1, 2]);
+    let b = Int32Array::from_slice(&[1]);
+
+    assert!(try_binary_or_null(&a, &b, DataType::Int32, checked_div).is_err());
+}
@@ -1,5 +1,7 @@
 use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
 use arrow2::compute::boolean::*;
+use arrow2::datatypes::DataType;
 use arrow2::scalar::BooleanScalar;
 use std::iter::FromIterator;
 
@@ -429,7 +431,7 @@ fn test_any_all() {
     assert!(!any(&array));
     assert!(!all(&array));
     let array = BooleanArray::from(&[None, Some(true), Some(true)]);
-    assert!(!all(&array));
+    assert!(all(&array));
     assert!(any(&array));
     let array = BooleanArray::from_iter(std::iter::repeat(false).take(10).map(Some));
     assert!(!any(&array));
@@ -446,4 +448,24 @@ fn test_any_all() {
     let array = BooleanArray::from(&[Some(false)]);
     assert!(!any(&array));
     assert!(!all(&array));
+    let array = BooleanArray::new_empty(DataType::Boolean);
+    assert!(!any(&array));
+    assert!(all(&array));
+    let array = BooleanArray::new_null(DataType::Boolean, 3);
+    assert!(!any(&array));
+    assert!(all(&array));
+}
+
+#[test]
+fn test_any_all_sliced() {
+    // the values under the nulls are `true` and `false` respectively and must be ignored
+    let array = BooleanArray::new(
+        DataType::Boolean,
+        Bitmap::from([false, true, false, false, true]),
+        Some(Bitmap::from([true, false, true, true, true])),
+    );
+    assert!(!any(&array.slice(0, 4)));
+    assert!(any(&array.slice(1, 4)));
+    assert!(all(&array.slice(1, 1)));
+    assert!(!all(&array.slice(1, 3)));
 }
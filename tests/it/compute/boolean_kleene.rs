@@ -214,3 +214,15 @@ fn array_or_none() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn array_no_validity_and_or_none() {
+    let array = BooleanArray::from_slice(&[true, false]);
+    let scalar = BooleanScalar::new(None);
+
+    let result = and_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from(&[None, Some(false)]));
+
+    let result = or_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from(&[Some(true), None]));
+}
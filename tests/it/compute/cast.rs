@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{can_cast_types, cast, try_utf8_to_primitive, CastOptions};
 use arrow2::datatypes::*;
 use arrow2::types::{days_ms, months_days_ns, NativeType};
 
@@ -21,10 +21,7 @@ fn i32_as_f64_no_overflow() {
     let b = cast(
         &array,
         &DataType::Float64,
-        CastOptions {
-            wrapped: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_wrapped(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
@@ -41,10 +38,7 @@ fn u16_as_u8_overflow() {
     let b = cast(
         &array,
         &DataType::UInt8,
-        CastOptions {
-            wrapped: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_wrapped(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
@@ -59,10 +53,7 @@ fn u16_as_u8_no_overflow() {
     let b = cast(
         &array,
         &DataType::UInt8,
-        CastOptions {
-            wrapped: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_wrapped(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
@@ -80,16 +71,30 @@ fn f32_as_u8_overflow() {
     let b = cast(
         &array,
         &DataType::UInt8,
-        CastOptions {
-            wrapped: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_wrapped(true),
     )
     .unwrap();
     let expected = UInt8Array::from(&[Some(1), Some(255)]);
     assert_eq!(expected, b.as_ref());
 }
 
+#[test]
+fn i64_to_i32_overflow() {
+    let array = Int64Array::from(&[Some(1), None, Some(i32::MAX as i64 + 1)]);
+
+    let b = cast(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, None]);
+    assert_eq!(expected, b.as_ref());
+
+    let options = CastOptions::default().with_checked(true);
+    assert!(cast(&array, &DataType::Int32, options).is_err());
+
+    let array = Int64Array::from(&[Some(1), None, Some(i32::MAX as i64)]);
+    let b = cast(&array, &DataType::Int32, options).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, Some(i32::MAX)]);
+    assert_eq!(expected, b.as_ref());
+}
+
 #[test]
 fn i32_to_u8() {
     let array = Int32Array::from_slice(&[-5, 6, -7, 8, 100000000]);
@@ -214,10 +219,7 @@ fn binary_to_i32_partial() {
     let b = cast(
         &array,
         &DataType::Int32,
-        CastOptions {
-            partial: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_partial(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
@@ -238,6 +240,36 @@ fn utf8_to_i32() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn utf8_to_i32_strict() {
+    let array = Utf8Array::<i32>::from(&[Some("5"), None, Some("-8")]);
+    let result = try_utf8_to_primitive::<i32, i32>(&array, &DataType::Int32).unwrap();
+    assert_eq!(result, Int32Array::from(&[Some(5), None, Some(-8)]));
+
+    let array = Utf8Array::<i64>::from_slice(&["5", "seven"]);
+    assert!(try_utf8_to_primitive::<i64, f64>(&array, &DataType::Float64).is_err());
+}
+
+#[test]
+fn utf8_to_i32_checked() {
+    let options = CastOptions::default().with_checked(true);
+    let array = Utf8Array::<i32>::from(&[Some("5"), None, Some("-8")]);
+    let result = cast(&array, &DataType::Int32, options).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from(&[Some(5), None, Some(-8)]) as &dyn Array
+    );
+
+    let array = Utf8Array::<i64>::from_slice(&["5", "seven"]);
+    assert!(cast(&array, &DataType::Float64, options).is_err());
+    // unchecked casts null unparsable values
+    let result = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Float64Array::from(&[Some(5.0), None]) as &dyn Array
+    );
+}
+
 #[test]
 fn int32_to_decimal() {
     // 10 and -10 can be represented with precision 1 and scale 0
@@ -366,10 +398,7 @@ fn utf8_to_i32_partial() {
     let b = cast(
         &array,
         &DataType::Int32,
-        CastOptions {
-            partial: true,
-            ..Default::default()
-        },
+        CastOptions::default().with_partial(true),
     )
     .unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
@@ -595,6 +624,50 @@ fn timestamp_to_date32() {
     );
 }
 
+#[test]
+fn date32_to_timestamp_and_back() {
+    let cases = [
+        (TimeUnit::Second, 86_400i64),
+        (TimeUnit::Millisecond, 86_400_000),
+        (TimeUnit::Microsecond, 86_400_000_000),
+        (TimeUnit::Nanosecond, 86_400_000_000_000),
+    ];
+    for (unit, per_day) in cases {
+        for tz in [None, Some("+01:00".to_string())] {
+            let timestamp_type = DataType::Timestamp(unit, tz);
+            let dates =
+                Int32Array::from(&[Some(0), None, Some(17890), Some(-1)]).to(DataType::Date32);
+            let timestamps =
+                Int64Array::from(&[Some(0), None, Some(17890 * per_day), Some(-per_day)])
+                    .to(timestamp_type.clone());
+
+            let result = cast(&dates, &timestamp_type, CastOptions::default()).unwrap();
+            assert_eq!(result.as_ref(), &timestamps as &dyn Array);
+
+            let result = cast(&timestamps, &DataType::Date32, CastOptions::default()).unwrap();
+            assert_eq!(result.as_ref(), &dates as &dyn Array);
+        }
+    }
+}
+
+#[test]
+fn date32_to_timestamp_overflow() {
+    // 300_000 days is in the year 2791, beyond what fits in `i64` nanoseconds
+    let dates = Int32Array::from(&[Some(300_000), Some(1), None]).to(DataType::Date32);
+    let timestamp_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let result = cast(&dates, &timestamp_type, CastOptions::default()).unwrap();
+    let expected =
+        Int64Array::from(&[None, Some(86_400_000_000_000), None]).to(timestamp_type.clone());
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // the same date fits in microseconds
+    let timestamp_type = DataType::Timestamp(TimeUnit::Microsecond, None);
+    let result = cast(&dates, &timestamp_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(300_000 * 86_400_000_000), Some(86_400_000_000), None])
+        .to(timestamp_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn timestamp_to_date64() {
     test_primitive_to_primitive(
@@ -696,6 +769,44 @@ fn list_to_list() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn list_to_large_list_and_back() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+    ];
+
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data.clone()).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let mut expected = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(data).unwrap();
+    let expected: ListArray<i64> = expected.into();
+
+    let result = cast(&array, expected.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+    let result = result.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+    // the values are shared
+    assert!(std::sync::Arc::ptr_eq(result.values(), array.values()));
+
+    let result = cast(result, array.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn large_list_to_list_overflow() {
+    let length = i32::MAX as usize + 1;
+    let values = std::sync::Arc::new(NullArray::new_null(DataType::Null, length))
+        as std::sync::Arc<dyn Array>;
+    let data_type = ListArray::<i64>::default_datatype(DataType::Null);
+    let array = ListArray::<i64>::new(data_type, vec![0, length as i64].into(), values, None);
+
+    let to_type = ListArray::<i32>::default_datatype(DataType::Null);
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+}
+
 #[test]
 fn timestamp_with_tz_to_utf8() {
     let tz = "-02:00".to_string();
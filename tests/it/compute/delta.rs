@@ -0,0 +1,48 @@
+use arrow2::array::*;
+use arrow2::compute::delta::{decode, encode};
+use arrow2::datatypes::DataType;
+
+#[test]
+fn round_trip() {
+    let array = Int64Array::from_slice([1, 2, 2, 5, 10]);
+    let encoded = encode(&array);
+    assert_eq!(encoded, Int64Array::from_slice([1, 1, 0, 3, 5]));
+    assert_eq!(decode(&encoded), array);
+}
+
+#[test]
+fn negative_deltas() {
+    let array = Int64Array::from_slice([10, 4, -3, -3, 7]);
+    let encoded = encode(&array);
+    assert_eq!(encoded, Int64Array::from_slice([10, -6, -7, 0, 10]));
+    assert_eq!(decode(&encoded), array);
+}
+
+#[test]
+fn with_nulls() {
+    let array = Int64Array::from(&[Some(1), None, Some(3)]);
+    let encoded = encode(&array);
+    assert_eq!(encoded.validity(), array.validity());
+    assert_eq!(decode(&encoded), array);
+}
+
+#[test]
+fn overflow() {
+    let array = Int64Array::from_slice([i64::MIN, i64::MAX, 0]);
+    assert_eq!(decode(&encode(&array)), array);
+}
+
+#[test]
+fn empty() {
+    let array = Int64Array::new_empty(DataType::Int64);
+    assert_eq!(encode(&array), array);
+    assert_eq!(decode(&array), array);
+}
+
+#[test]
+fn sliced() {
+    let array = Int64Array::from_slice([1, 2, 4, 8]).slice(1, 3);
+    let encoded = encode(&array);
+    assert_eq!(encoded, Int64Array::from_slice([2, 2, 4]));
+    assert_eq!(decode(&encoded), array);
+}
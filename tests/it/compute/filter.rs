@@ -128,6 +128,51 @@ fn masked_true_values() {
     assert_eq!(expected, c.as_ref());
 }
 
+#[test]
+fn struct_array() {
+    use std::sync::Arc;
+
+    use arrow2::datatypes::{DataType, Field};
+
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let array = StructArray::new(
+        data_type.clone(),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[
+                Some("a"),
+                Some("b"),
+                None,
+                Some("d"),
+            ])),
+        ],
+        Some(Bitmap::from([true, true, false, true])),
+    );
+    let mask = BooleanArray::from(&[Some(false), Some(true), Some(true), None]);
+
+    let expected = StructArray::new(
+        data_type,
+        vec![
+            Arc::new(Int32Array::from(&[None, Some(3)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("b"), None])),
+        ],
+        Some(Bitmap::from([true, false])),
+    );
+
+    let result = filter_struct(&array, &mask).unwrap();
+    assert_eq!(result, expected);
+
+    // dispatched by `filter`
+    let result = filter(&array, &mask).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let mask = BooleanArray::from_slice([true, false]);
+    assert!(filter_struct(&array, &mask).is_err());
+}
+
 /*
 #[test]
 fn dictionary_array() {
@@ -193,3 +238,89 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+fn new_list<O: Offset>(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<O> {
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn list_array() {
+    let array = new_list::<i32>(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![Some(3)]),
+        Some(vec![]),
+        Some(vec![Some(4), None, Some(5)]),
+    ]);
+    let mask = BooleanArray::from(&[Some(true), Some(true), Some(false), None, Some(true)]);
+
+    let result = filter_list(&array, &mask).unwrap();
+
+    let expected = new_list::<i32>(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![Some(4), None, Some(5)]),
+    ]);
+    assert_eq!(result, expected);
+    // filtered out entries are not kept in the child values
+    assert_eq!(result.values().len(), 5);
+
+    let result = filter(&array, &mask).unwrap();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn large_list_array_sliced() {
+    let array = new_list::<i64>(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3)]),
+        None,
+        Some(vec![Some(4), Some(5)]),
+        Some(vec![Some(6)]),
+    ])
+    .slice(1, 4);
+    let mask = BooleanArray::from_slice(&[false, true, true, false]);
+
+    let result = filter(&array, &mask).unwrap();
+
+    let expected = new_list::<i64>(vec![None, Some(vec![Some(4), Some(5)])]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn list_array_different_lengths() {
+    let array = new_list::<i32>(vec![Some(vec![Some(1)])]);
+    let mask = BooleanArray::from_slice(&[true, false]);
+
+    assert!(filter_list(&array, &mask).is_err());
+}
+
+#[test]
+fn mask_to_indices_sparse() {
+    let mask = (0..200)
+        .map(|i| match i % 50 {
+            0 => None,
+            7 | 49 => Some(true),
+            _ => Some(false),
+        })
+        .collect::<BooleanArray>();
+    let expected = (0..200)
+        .filter(|i| matches!(i % 50, 7 | 49))
+        .collect::<Vec<i32>>();
+    assert_eq!(mask_to_indices(&mask), Int32Array::from_vec(expected));
+
+    // sliced masks are not aligned with the chunks of 64 bits
+    let indices = mask_to_indices(&mask.slice(5, 50));
+    assert_eq!(indices, Int32Array::from_slice(&[2, 44]));
+}
+
+#[test]
+fn mask_to_indices_empty() {
+    let mask = BooleanArray::from_slice(&[false, false]);
+    assert_eq!(mask_to_indices(&mask), Int32Array::from_slice(&[]));
+
+    let mask = BooleanArray::from_slice(&[] as &[bool]);
+    assert_eq!(mask_to_indices(&mask), Int32Array::from_slice(&[]));
+}
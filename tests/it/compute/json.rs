@@ -0,0 +1,37 @@
+use arrow2::array::Utf8Array;
+use arrow2::compute::json::extract;
+
+#[test]
+fn extract_nested() {
+    let array = Utf8Array::<i32>::from_slice(&[r#"{"a":{"b":"x"}}"#, r#"{"a":{"b":{"c":1}}}"#]);
+    let result = extract(&array, "a.b").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(&["x", r#"{"c":1}"#]));
+}
+
+#[test]
+fn extract_missing_is_null() {
+    let array = Utf8Array::<i64>::from(&[
+        Some(r#"{"a":{"b":"x"}}"#),
+        Some(r#"{"a":{"c":"x"}}"#),
+        Some(r#"{"a":"b"}"#),
+        Some(r#"{"a":{"b":null}}"#),
+        Some("not json"),
+        None,
+    ]);
+    let result = extract(&array, "a.b").unwrap();
+    let expected = Utf8Array::<i64>::from(&[Some("x"), None, None, None, None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn extract_array_index() {
+    let array = Utf8Array::<i32>::from_slice(&[r#"{"a":[1,2.5,true]}"#, r#"{"a":[]}"#]);
+    let result = extract(&array, "a.1").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from(&[Some("2.5"), None]));
+}
+
+#[test]
+fn extract_empty_path() {
+    let array = Utf8Array::<i32>::from_slice(&["{}"]);
+    assert!(extract(&array, "").is_err());
+}
@@ -40,6 +40,23 @@ fn utf8() {
     length_test_string::<i32>()
 }
 
+#[test]
+fn string_lengths() {
+    let array = Utf8Array::<i32>::from(&[Some("hello"), Some(""), None, Some("💖a"), Some("ñé")]);
+    assert_eq!(
+        string_length(&array),
+        Int32Array::from(&[Some(5), Some(0), None, Some(2), Some(2)])
+    );
+    assert_eq!(
+        string_byte_length(&array),
+        Int32Array::from(&[Some(5), Some(0), None, Some(5), Some(4)])
+    );
+
+    let array = Utf8Array::<i64>::from(&[Some("日本"), Some("x")]).slice(1, 1);
+    assert_eq!(string_length(&array), Int32Array::from_slice(&[1]));
+    assert_eq!(string_byte_length(&array), Int32Array::from_slice(&[1]));
+}
+
 #[test]
 fn consistency() {
     use arrow2::datatypes::DataType::*;
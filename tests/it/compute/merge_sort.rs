@@ -228,3 +228,44 @@ fn test_sort() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn merge_sorted_by_primitive() {
+    use arrow2::array::ord::{total_cmp, total_cmp_f64};
+
+    let a = Int32Array::from_slice(&[0, 2, 2, 5]);
+    let b = Int32Array::from_slice(&[1, 2, 6]);
+    let result = merge_sorted_by(&a, &b, total_cmp, &SortOptions::default());
+    assert_eq!(result, Int32Array::from_slice(&[0, 1, 2, 2, 2, 5, 6]));
+
+    let a = Float64Array::from_slice(&[]);
+    let b = Float64Array::from_slice(&[1.0, 2.0]);
+    let result = merge_sorted_by(&a, &b, total_cmp_f64, &SortOptions::default());
+    assert_eq!(result, b);
+}
+
+#[test]
+fn merge_sorted_by_nulls() {
+    use arrow2::array::ord::total_cmp;
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let a = Int32Array::from(&[Some(5), Some(1), None]);
+    let b = Int32Array::from(&[Some(4), Some(3), None, None]);
+    let result = merge_sorted_by(&a, &b, total_cmp, &options);
+    let expected = Int32Array::from(&[Some(5), Some(4), Some(3), Some(1), None, None, None]);
+    assert_eq!(result, expected);
+    assert_eq!(result.len(), a.len() + b.len());
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let a = Int32Array::from(&[None, Some(1), Some(5)]);
+    let b = Int32Array::from(&[None, Some(3)]);
+    let result = merge_sorted_by(&a, &b, total_cmp, &options);
+    let expected = Int32Array::from(&[None, None, Some(1), Some(3), Some(5)]);
+    assert_eq!(result, expected);
+}
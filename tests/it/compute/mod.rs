@@ -2,6 +2,7 @@
 mod aggregate;
 #[cfg(feature = "compute_arithmetics")]
 mod arithmetics;
+mod arity;
 #[cfg(feature = "compute_bitwise")]
 mod bitwise;
 #[cfg(feature = "compute_boolean")]
@@ -16,12 +17,16 @@ mod comparison;
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_delta")]
+mod delta;
 #[cfg(feature = "compute_filter")]
 mod filter;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
+#[cfg(feature = "compute_json")]
+mod json;
 #[cfg(feature = "compute_length")]
 mod length;
 #[cfg(feature = "compute_like")]
@@ -30,10 +35,14 @@ mod like;
 mod limit;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_nullif")]
+mod nullif;
 #[cfg(feature = "compute_partition")]
 mod partition;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_row")]
+mod row;
 #[cfg(feature = "compute_sort")]
 mod sort;
 #[cfg(feature = "compute_substring")]
@@ -0,0 +1,23 @@
+use arrow2::array::*;
+use arrow2::compute::nullif::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{NullScalar, PrimitiveScalar};
+
+#[test]
+fn scalar() {
+    let lhs = Int32Array::from(&[None, Some(0), Some(1)]);
+    let rhs = PrimitiveScalar::<i32>::from(Some(0));
+    let expected = Int32Array::from(&[None, None, Some(1)]);
+    assert_eq!(expected, nullif_scalar(&lhs, &rhs).as_ref());
+}
+
+#[test]
+fn scalar_null() {
+    let lhs = Int32Array::from(&[None, Some(0), Some(1)]);
+
+    let rhs = PrimitiveScalar::<i32>::from(None);
+    assert_eq!(lhs, nullif_scalar(&lhs, &rhs).as_ref());
+
+    let rhs = NullScalar::new_typed(DataType::Int32);
+    assert_eq!(lhs, nullif_scalar(&lhs, &rhs).as_ref());
+}
@@ -0,0 +1,173 @@
+use arrow2::array::*;
+use arrow2::compute::row::{decode, encode};
+use arrow2::compute::sort::{lexsort, SortColumn, SortOptions};
+use arrow2::compute::take::take;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+/// asserts that sorting the rows by their encoding equals a lexsort of the columns
+fn check(columns: &[&dyn Array], options: &[SortOptions]) -> Result<()> {
+    let rows = encode(columns, options)?;
+    assert_eq!(rows.len(), columns[0].len());
+
+    let mut indices = (0..rows.len() as u32).collect::<Vec<_>>();
+    indices.sort_by(|a, b| rows.value(*a as usize).cmp(rows.value(*b as usize)));
+    let indices = UInt32Array::from_vec(indices);
+
+    let sort_columns = columns
+        .iter()
+        .zip(options.iter())
+        .map(|(values, options)| SortColumn {
+            values: *values,
+            options: Some(*options),
+        })
+        .collect::<Vec<_>>();
+    let expected = lexsort::<u32>(&sort_columns, None)?;
+
+    for (column, expected) in columns.iter().zip(expected.iter()) {
+        let result = take(*column, &indices)?;
+        assert_eq!(result.as_ref(), expected.as_ref());
+    }
+    Ok(())
+}
+
+fn all_options() -> Vec<SortOptions> {
+    let mut options = vec![];
+    for descending in [false, true] {
+        for nulls_first in [false, true] {
+            options.push(SortOptions {
+                descending,
+                nulls_first,
+            })
+        }
+    }
+    options
+}
+
+#[test]
+fn primitive_and_utf8() -> Result<()> {
+    let a = Int32Array::from(&[Some(1), None, Some(-1), Some(1), Some(0), None, Some(1)]);
+    let b = Utf8Array::<i32>::from(&[
+        Some("b"),
+        Some("a"),
+        None,
+        Some("ab"),
+        Some(""),
+        Some("a\0"),
+        Some("b"),
+    ]);
+    for a_options in all_options() {
+        for b_options in all_options() {
+            check(&[&a, &b], &[a_options, b_options])?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn floats_and_booleans() -> Result<()> {
+    let a = Float64Array::from(&[
+        Some(1.0),
+        Some(-1.0),
+        None,
+        Some(0.0),
+        Some(-0.5),
+        Some(1.0),
+        Some(f64::INFINITY),
+    ]);
+    let b = BooleanArray::from(&[
+        Some(true),
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+        Some(false),
+        None,
+    ]);
+    for a_options in all_options() {
+        for b_options in all_options() {
+            check(&[&a, &b], &[a_options, b_options])?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn binary_and_unsigned() -> Result<()> {
+    let a = BinaryArray::<i64>::from(&[
+        Some(b"\0\0".as_ref()),
+        Some(b"\0".as_ref()),
+        None,
+        Some(b"".as_ref()),
+        Some(b"\xff".as_ref()),
+    ]);
+    let b = UInt64Array::from(&[Some(u64::MAX), Some(0), Some(1), None, Some(2)]);
+    for a_options in all_options() {
+        for b_options in all_options() {
+            check(&[&a, &b], &[a_options, b_options])?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(encode(&[&a, &b], &[SortOptions::default(); 2]).is_err());
+    assert!(encode(&[&a], &[SortOptions::default(); 2]).is_err());
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let a = Int32Array::from(&[Some(1), None, Some(-1), Some(i32::MIN), Some(i32::MAX)]);
+    let b = Utf8Array::<i32>::from(&[Some("b"), Some("a\0"), None, Some(""), Some("aaa")]);
+    let c = Float32Array::from(&[Some(-0.0), Some(1.5), None, Some(-2.5), Some(f32::MAX)]);
+    let d = BooleanArray::from(&[Some(true), None, Some(false), Some(true), None]);
+    let e = BinaryArray::<i64>::from(&[
+        Some(b"\0".as_ref()),
+        None,
+        Some(b"\xff\0".as_ref()),
+        Some(b"".as_ref()),
+        Some(b"a".as_ref()),
+    ]);
+    let columns: [&dyn Array; 5] = [&a, &b, &c, &d, &e];
+    let fields = columns
+        .iter()
+        .map(|column| Field::new("", column.data_type().clone(), true))
+        .collect::<Vec<_>>();
+
+    for options in all_options() {
+        let options = [options; 5];
+        let rows = encode(&columns, &options)?;
+        let result = decode(&rows, &fields, &options)?;
+        for (column, result) in columns.iter().zip(result.iter()) {
+            assert_eq!(*column, result.as_ref());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn round_trip_logical_type() -> Result<()> {
+    let a = Int32Array::from(&[Some(1), None]).to(DataType::Date32);
+    let fields = [Field::new("a", DataType::Date32, true)];
+    let options = [SortOptions::default()];
+    let rows = encode(&[&a], &options)?;
+    let result = decode(&rows, &fields, &options)?;
+    assert_eq!(result[0].as_ref(), &a as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn decode_invalid() {
+    let a = Int32Array::from_slice([1]);
+    let options = [SortOptions::default()];
+    let rows = encode(&[&a], &options).unwrap();
+    // rows have more data than a single boolean column
+    let fields = [Field::new("a", DataType::Boolean, true)];
+    assert!(decode(&rows, &fields, &options).is_err());
+    // rows have less data than a i64 column
+    let fields = [Field::new("a", DataType::Int64, true)];
+    assert!(decode(&rows, &fields, &options).is_err());
+}
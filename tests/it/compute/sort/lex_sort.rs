@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::sort::{lexsort, SortColumn, SortOptions};
+use arrow2::compute::sort::{lexsort, lexsort_to_indices, SortColumn, SortOptions};
 
 fn test_lex_sort_arrays(input: Vec<SortColumn>, expected: Vec<Box<dyn Array>>) {
     let sorted = lexsort::<i32>(&input, None).unwrap();
@@ -73,6 +73,112 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 
+#[test]
+fn lex_sort_to_indices_utf8() {
+    let c1 = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("b"), None]);
+    let c2 = Utf8Array::<i64>::from(&[Some("y"), Some("x"), Some("z"), Some("x"), Some("w")]);
+    let input = vec![
+        SortColumn {
+            values: &c1,
+            options: Some(SortOptions {
+                descending: false,
+                nulls_first: false,
+            }),
+        },
+        SortColumn {
+            values: &c2,
+            options: Some(SortOptions {
+                descending: false,
+                nulls_first: true,
+            }),
+        },
+    ];
+    let indices = lexsort_to_indices::<i32>(&input, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([2, 3, 0, 4, 1]));
+}
+
+#[test]
+fn lex_sort_to_indices_u32_take() {
+    use arrow2::compute::take::take;
+
+    // ORDER BY a ASC NULLS FIRST, b DESC NULLS LAST
+    let a = Int32Array::from(&[Some(1), None, Some(0), Some(1), Some(1)]);
+    let b = Utf8Array::<i32>::from(&[Some("x"), Some("y"), Some("z"), None, Some("y")]);
+    let input = vec![
+        SortColumn {
+            values: &a,
+            options: Some(SortOptions {
+                descending: false,
+                nulls_first: true,
+            }),
+        },
+        SortColumn {
+            values: &b,
+            options: Some(SortOptions {
+                descending: true,
+                nulls_first: false,
+            }),
+        },
+    ];
+    let indices = lexsort_to_indices::<u32>(&input, None).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([1, 2, 4, 0, 3]));
+
+    let a = take(&a, &indices).unwrap();
+    let b = take(&b, &indices).unwrap();
+    assert_eq!(
+        Int32Array::from(&[None, Some(0), Some(1), Some(1), Some(1)]),
+        a.as_ref()
+    );
+    assert_eq!(
+        Utf8Array::<i32>::from(&[Some("y"), Some("z"), Some("y"), Some("x"), None]),
+        b.as_ref()
+    );
+}
+
+#[test]
+fn lex_sort_to_indices_stable() {
+    // rows 0, 2 and 5 and rows 1 and 4 are equal on all columns
+    let a = Int32Array::from(&[Some(1), None, Some(1), Some(0), None, Some(1)]);
+    let b = Utf8Array::<i32>::from(&[Some("x"), None, Some("x"), Some("y"), None, Some("x")]);
+    let input = vec![
+        SortColumn {
+            values: &a,
+            options: Some(SortOptions {
+                descending: true,
+                nulls_first: false,
+            }),
+        },
+        SortColumn {
+            values: &b,
+            options: None,
+        },
+    ];
+    let indices = lexsort_to_indices::<i32>(&input, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([0, 2, 5, 3, 1, 4]));
+
+    let indices = lexsort_to_indices::<i32>(&input, Some(2)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([0, 2]));
+}
+
+#[test]
+fn lex_sort_to_indices_stable_single_column() {
+    // enough equal values for an unstable sort to reorder them
+    let a = Int32Array::from_iter((0..100).map(|x| Some(x % 3)));
+    let input = vec![SortColumn {
+        values: &a,
+        options: None,
+    }];
+    let expected = (0..3)
+        .flat_map(|value| (0..100).filter(move |x| x % 3 == value))
+        .collect::<Vec<i32>>();
+
+    let indices = lexsort_to_indices::<i32>(&input, None).unwrap();
+    assert_eq!(indices, Int32Array::from_vec(expected.clone()));
+
+    let indices = lexsort_to_indices::<i32>(&input, Some(10)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice(&expected[..10]));
+}
+
 /*
     // test sort with nulls first
     let input = vec![
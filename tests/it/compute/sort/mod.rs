@@ -1,4 +1,5 @@
 mod lex_sort;
+mod rank;
 
 use arrow2::array::*;
 use arrow2::compute::sort::*;
@@ -139,6 +140,60 @@ fn boolean() {
     );
 }
 
+#[test]
+fn boolean_values() {
+    let data = &[Some(true), None, Some(false), Some(true), None, Some(false)];
+    let cases: &[(bool, bool, Option<usize>, &[Option<bool>])] = &[
+        (
+            false,
+            false,
+            None,
+            &[Some(false), Some(false), Some(true), Some(true), None, None],
+        ),
+        (
+            false,
+            true,
+            None,
+            &[None, None, Some(false), Some(false), Some(true), Some(true)],
+        ),
+        (
+            true,
+            false,
+            None,
+            &[Some(true), Some(true), Some(false), Some(false), None, None],
+        ),
+        (
+            true,
+            true,
+            None,
+            &[None, None, Some(true), Some(true), Some(false), Some(false)],
+        ),
+        (false, true, Some(3), &[None, None, Some(false)]),
+        (true, false, Some(1), &[Some(true)]),
+    ];
+    for (descending, nulls_first, limit, expected) in cases {
+        let options = SortOptions {
+            descending: *descending,
+            nulls_first: *nulls_first,
+        };
+        let input = BooleanArray::from(data);
+        let output = sort(&input, &options, *limit).unwrap();
+        assert_eq!(BooleanArray::from(*expected), output.as_ref());
+    }
+
+    // without validity
+    let input = BooleanArray::from_slice([true, false, true]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let output = sort(&input, &options, None).unwrap();
+    assert_eq!(
+        BooleanArray::from_slice([false, true, true]),
+        output.as_ref()
+    );
+}
+
 #[test]
 #[ignore] // improve equality for NaN values. These are right but the equality fails
 fn test_nans() {
@@ -348,6 +403,126 @@ fn strings() {
     );
 }
 
+#[test]
+fn strings_limit() {
+    let data = &[
+        None,
+        Some("bad"),
+        Some("sad"),
+        None,
+        Some("glad"),
+        Some("-ad"),
+    ];
+    let cases: &[(bool, bool, usize, &[Option<&str>])] = &[
+        (false, true, 3, &[None, None, Some("-ad")]),
+        (false, true, 1, &[None]),
+        (false, false, 2, &[Some("-ad"), Some("bad")]),
+        (true, false, 3, &[Some("sad"), Some("glad"), Some("bad")]),
+        (true, true, 4, &[None, None, Some("sad"), Some("glad")]),
+        (
+            false,
+            false,
+            5,
+            &[Some("-ad"), Some("bad"), Some("glad"), Some("sad"), None],
+        ),
+    ];
+    for (descending, nulls_first, limit, expected) in cases {
+        let options = SortOptions {
+            descending: *descending,
+            nulls_first: *nulls_first,
+        };
+        let input = Utf8Array::<i64>::from(data);
+        let output = sort(&input, &options, Some(*limit)).unwrap();
+        assert_eq!(Utf8Array::<i64>::from(*expected), output.as_ref());
+    }
+}
+
+#[test]
+fn strings_options_permutations() {
+    let data = &[Some("b"), None, Some("a"), Some("c"), None];
+    let cases: &[(bool, bool, &[Option<&str>], &[i32])] = &[
+        (
+            false,
+            false,
+            &[Some("a"), Some("b"), Some("c"), None, None],
+            &[2, 0, 3, 1, 4],
+        ),
+        (
+            false,
+            true,
+            &[None, None, Some("a"), Some("b"), Some("c")],
+            &[1, 4, 2, 0, 3],
+        ),
+        (
+            true,
+            false,
+            &[Some("c"), Some("b"), Some("a"), None, None],
+            &[3, 0, 2, 1, 4],
+        ),
+        (
+            true,
+            true,
+            &[None, None, Some("c"), Some("b"), Some("a")],
+            &[1, 4, 3, 0, 2],
+        ),
+    ];
+    for (descending, nulls_first, expected, expected_indices) in cases {
+        let options = SortOptions {
+            descending: *descending,
+            nulls_first: *nulls_first,
+        };
+        string_arrays(data, options, expected);
+        to_indices_string_arrays(data, options, expected_indices);
+    }
+}
+
+#[test]
+fn binary() {
+    let input = BinaryArray::<i32>::from_iter([Some("b"), None, Some("a"), Some("ab")]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let output = sort(&input, &options, None).unwrap();
+    let expected = BinaryArray::<i32>::from_iter([Some("a"), Some("ab"), Some("b"), None]);
+    assert_eq!(expected, output.as_ref());
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let input = BinaryArray::<i64>::from_iter(input.iter());
+    let output = sort(&input, &options, Some(2)).unwrap();
+    let expected = BinaryArray::<i64>::from_iter([None, Some("b")]);
+    assert_eq!(expected, output.as_ref());
+}
+
+#[test]
+fn fixed_size_binary() {
+    let input = FixedSizeBinaryArray::from([Some([1, 2]), None, Some([0, 3]), Some([1, 1])]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let output = sort(&input, &options, None).unwrap();
+    let expected = FixedSizeBinaryArray::from([Some([0, 3]), Some([1, 1]), Some([1, 2]), None]);
+    assert_eq!(expected, output.as_ref());
+
+    let indices = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice(&[2, 3, 0, 1]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let output = sort(&input, &options, Some(2)).unwrap();
+    let expected = FixedSizeBinaryArray::from([None, Some([1, 2])]);
+    assert_eq!(expected, output.as_ref());
+
+    let indices = sort_to_indices::<i32>(&input, &options, Some(2)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice(&[1, 0]));
+}
+
 #[test]
 fn string_dicts() {
     string_dict_arrays::<i8>(
@@ -603,3 +778,56 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn to_indices_dyn() {
+    let options = SortOptions::default();
+    let arrays: Vec<Box<dyn Array>> = vec![
+        Box::new(Int32Array::from(&[Some(3), None, Some(1)])),
+        Box::new(Utf8Array::<i32>::from(&[Some("c"), None, Some("a")])),
+        Box::new(BooleanArray::from(&[Some(true), None, Some(false)])),
+    ];
+
+    for array in arrays {
+        let result = sort_to_indices::<i32>(array.as_ref(), &options, None).unwrap();
+        assert_eq!(result, Int32Array::from_slice(&[1, 2, 0]));
+    }
+}
+
+#[test]
+fn to_indices_nested_not_implemented() {
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let array = new_null_array(data_type, 2);
+
+    let result = sort_to_indices::<i32>(array.as_ref(), &SortOptions::default(), None);
+    assert!(matches!(
+        result,
+        Err(arrow2::error::ArrowError::NotYetImplemented(_))
+    ));
+}
+
+#[test]
+fn is_sorted_dyn() {
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let array = Utf8Array::<i32>::from(&[Some("a"), Some("a"), Some("b"), None]);
+    assert!(is_sorted(&array, &options).unwrap());
+    let array = Utf8Array::<i32>::from(&[Some("b"), Some("a")]);
+    assert!(!is_sorted(&array, &options).unwrap());
+
+    let array = Float64Array::from(&[Some(1.0), None, Some(2.0)]);
+    assert!(!is_sorted(&array, &options).unwrap());
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let array = Int64Array::from(&[None, Some(3), Some(3), Some(-1)]);
+    assert!(is_sorted(&array, &options).unwrap());
+
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let array = new_null_array(data_type, 2);
+    assert!(is_sorted(array.as_ref(), &options).is_err());
+}
@@ -0,0 +1,60 @@
+use arrow2::array::ord::total_cmp;
+use arrow2::array::*;
+use arrow2::compute::sort::{rank, RankMethod, SortOptions};
+
+fn rank_i32(data: &[Option<i32>], method: RankMethod, descending: bool) -> Float64Array {
+    let array = Int32Array::from(data);
+    let options = SortOptions {
+        descending,
+        nulls_first: true,
+    };
+    rank(&array, total_cmp, method, &options)
+}
+
+#[test]
+fn methods() {
+    let data = &[Some(10), Some(20), Some(20), Some(30)];
+
+    let result = rank_i32(data, RankMethod::Dense, false);
+    assert_eq!(result, Float64Array::from_slice(&[1.0, 2.0, 2.0, 3.0]));
+
+    let result = rank_i32(data, RankMethod::Min, false);
+    assert_eq!(result, Float64Array::from_slice(&[1.0, 2.0, 2.0, 4.0]));
+
+    let result = rank_i32(data, RankMethod::Max, false);
+    assert_eq!(result, Float64Array::from_slice(&[1.0, 3.0, 3.0, 4.0]));
+
+    let result = rank_i32(data, RankMethod::Average, false);
+    assert_eq!(result, Float64Array::from_slice(&[1.0, 2.5, 2.5, 4.0]));
+}
+
+#[test]
+fn descending() {
+    let data = &[Some(10), Some(20), Some(20), Some(30)];
+
+    let result = rank_i32(data, RankMethod::Min, true);
+    assert_eq!(result, Float64Array::from_slice(&[4.0, 2.0, 2.0, 1.0]));
+}
+
+#[test]
+fn nulls() {
+    let data = &[Some(3), None, Some(1), Some(3), None];
+
+    let result = rank_i32(data, RankMethod::Dense, false);
+    assert_eq!(
+        result,
+        Float64Array::from(&[Some(2.0), None, Some(1.0), Some(2.0), None])
+    );
+
+    let result = rank_i32(data, RankMethod::Average, false);
+    assert_eq!(
+        result,
+        Float64Array::from(&[Some(2.5), None, Some(1.0), Some(2.5), None])
+    );
+}
+
+#[test]
+fn empty() {
+    let result = rank_i32(&[], RankMethod::Dense, false);
+    assert_eq!(result, Float64Array::from_slice(&[]));
+}
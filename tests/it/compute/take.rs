@@ -108,6 +108,55 @@ fn test_struct_with_nulls() {
     assert_eq!(expected, output.as_ref());
 }
 
+#[test]
+fn struct_empty() {
+    let array = create_test_struct();
+
+    let indices = Int32Array::from_slice(&[]);
+
+    let output = take(&array, &indices).unwrap();
+
+    assert_eq!(output.len(), 0);
+    assert_eq!(output.data_type(), array.data_type());
+
+    let empty = array.slice(0, 0);
+    let output = take(&empty, &indices).unwrap();
+    assert_eq!(empty, output.as_ref());
+}
+
+#[test]
+fn struct_all_nulls() {
+    let array = new_null_array(create_test_struct().data_type().clone(), 3);
+
+    let indices = Int32Array::from(&[Some(2), None, Some(0), Some(1)]);
+
+    let output = take(array.as_ref(), &indices).unwrap();
+
+    let expected = new_null_array(array.data_type().clone(), 4);
+    assert_eq!(expected.as_ref(), output.as_ref());
+    assert_eq!(output.null_count(), 4);
+}
+
+#[test]
+fn dictionary() {
+    let keys = Int32Array::from(&[Some(1), None, Some(0), Some(1)]);
+    let values = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"])) as Arc<dyn Array>;
+    let array = DictionaryArray::<i32>::from_data(keys, values.clone());
+
+    let indices = Int32Array::from(&[Some(3), Some(1), None, Some(2)]);
+
+    let output = take(&array, &indices).unwrap();
+    let output = output
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    let expected_keys = Int32Array::from(&[Some(1), None, None, Some(0)]);
+    assert_eq!(output.keys(), &expected_keys);
+    // the dictionary values are shared, not rebuilt
+    assert!(Arc::ptr_eq(output.values(), &values));
+}
+
 #[test]
 fn consistency() {
     use arrow2::array::new_null_array;
@@ -369,3 +369,119 @@ fn consistency_upper() {
         }
     });
 }
+
+#[test]
+fn unicode_case() -> Result<()> {
+    // full unicode case mapping can change the number of bytes and characters
+    let array = Utf8Array::<i32>::from(&[Some("straße"), None, Some("ǅemal"), Some("ΣΑΣ")]);
+
+    let result = upper(&array)?;
+    let expected = Utf8Array::<i32>::from(&[Some("STRASSE"), None, Some("ǄEMAL"), Some("ΣΑΣ")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = lower(&array.slice(1, 3))?;
+    let expected = Utf8Array::<i32>::from(&[None, Some("ǆemal"), Some("σας")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn test_count_matches() {
+    let array =
+        Utf8Array::<i32>::from(&[Some("banana"), None, Some(""), Some("aaaa"), Some("xyz")]);
+    let result = count_matches(&array, "a");
+    let expected = Int32Array::from(&[Some(3), None, Some(0), Some(4), Some(0)]);
+    assert_eq!(result, expected);
+
+    // non-overlapping
+    let result = count_matches(&array, "aa");
+    let expected = Int32Array::from(&[Some(0), None, Some(0), Some(2), Some(0)]);
+    assert_eq!(result, expected);
+
+    let array = Utf8Array::<i64>::from_slice(&["banana", ""]);
+    let result = count_matches(&array, "");
+    assert_eq!(result, Int32Array::from_slice(&[0, 0]));
+}
+
+#[test]
+fn test_split_part() {
+    let array = Utf8Array::<i32>::from(&[Some("a.b.c"), None, Some("a"), Some(""), Some("a..b")]);
+
+    let result = split_part(&array, ".", 2);
+    let expected = Utf8Array::<i32>::from(&[Some("b"), None, Some(""), Some(""), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = split_part(&array, ".", -1);
+    let expected = Utf8Array::<i32>::from(&[Some("c"), None, Some("a"), Some(""), Some("b")]);
+    assert_eq!(result, expected);
+
+    let result = split_part(&array, ".", 1);
+    let expected = Utf8Array::<i32>::from(&[Some("a"), None, Some("a"), Some(""), Some("a")]);
+    assert_eq!(result, expected);
+
+    let result = split_part(&array, ".", 4);
+    let expected = Utf8Array::<i32>::from(&[Some(""), None, Some(""), Some(""), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = split_part(&array, ".", 0);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_split_part_large() {
+    let array = Utf8Array::<i64>::from_slice(&["ab::cd::ef", "ab"]);
+
+    let result = split_part(&array, "::", -2);
+    assert_eq!(result, Utf8Array::<i64>::from_slice(&["cd", ""]));
+
+    let result = split_part(&array, "", 1);
+    assert_eq!(result, array);
+    let result = split_part(&array, "", 2);
+    assert_eq!(result, Utf8Array::<i64>::from_slice(&["", ""]));
+}
+
+#[test]
+fn test_contains_scalar() {
+    let array = Utf8Array::<i32>::from(&[Some("arrow"), None, Some(""), Some("über"), Some("row")]);
+    let result = contains_scalar(&array, "ro");
+    let expected = BooleanArray::from(&[Some(true), None, Some(false), Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = starts_with_scalar(&array, "ro");
+    let expected = BooleanArray::from(&[Some(false), None, Some(false), Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = ends_with_scalar(&array, "ow");
+    let expected = BooleanArray::from(&[Some(true), None, Some(false), Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    // non-ascii and empty patterns
+    let result = starts_with_scalar(&array, "ü");
+    let expected = BooleanArray::from(&[Some(false), None, Some(false), Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = contains_scalar(&array, "");
+    let expected = BooleanArray::from(&[Some(true), None, Some(true), Some(true), Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_contains() -> Result<()> {
+    let array = Utf8Array::<i64>::from(&[Some("arrow"), None, Some("arrow"), Some("arrow")]);
+    let patterns = Utf8Array::<i64>::from(&[Some("rr"), Some("a"), None, Some("w")]);
+
+    let result = contains(&array, &patterns)?;
+    let expected = BooleanArray::from(&[Some(true), None, None, Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = starts_with(&array, &patterns)?;
+    let expected = BooleanArray::from(&[Some(false), None, None, Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = ends_with(&array, &patterns)?;
+    let expected = BooleanArray::from(&[Some(false), None, None, Some(true)]);
+    assert_eq!(result, expected);
+
+    assert!(contains(&array, &patterns.slice(0, 3)).is_err());
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use arrow2::datatypes::{schema_diff, DataType, Field, Schema, SchemaDifference};
+
+#[test]
+fn schema_diff_changed_and_added() {
+    let before = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let after = Schema::from(vec![
+        Field::new("b", DataType::LargeUtf8, false),
+        Field::new("a", DataType::Int32, true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+
+    let expected = vec![
+        SchemaDifference::Changed {
+            before: Field::new("b", DataType::Utf8, false),
+            after: Field::new("b", DataType::LargeUtf8, false),
+        },
+        SchemaDifference::Added(Field::new("c", DataType::Boolean, true)),
+    ];
+    assert_eq!(schema_diff(&before, &after), expected);
+}
+
+#[test]
+fn schema_diff_removed() {
+    let before = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let after = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+
+    let expected = vec![
+        SchemaDifference::Changed {
+            before: Field::new("a", DataType::Int32, true),
+            after: Field::new("a", DataType::Int32, false),
+        },
+        SchemaDifference::Removed(Field::new("b", DataType::Utf8, false)),
+    ];
+    assert_eq!(schema_diff(&before, &after), expected);
+
+    assert!(schema_diff(&before, &before).is_empty());
+}
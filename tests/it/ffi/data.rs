@@ -1,6 +1,8 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, IntegerType, TimeUnit};
+use arrow2::types::months_days_ns;
 use arrow2::{error::Result, ffi};
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -90,6 +92,26 @@ fn timestamp_tz() -> Result<()> {
     test_round_trip(data)
 }
 
+#[test]
+fn months_days_ns() -> Result<()> {
+    let data = MonthsDaysNsArray::from(&[
+        Some(months_days_ns::new(1, 1, 2)),
+        None,
+        Some(months_days_ns::new(-1, 0, -3)),
+    ]);
+    test_round_trip(data)
+}
+
+#[test]
+fn decimal256() -> Result<()> {
+    let mut one = [0u8; 32];
+    one[0] = 1;
+    let minus_one = [255u8; 32];
+    let data = FixedSizeBinaryArray::from([Some(one), None, Some(minus_one)])
+        .to(DataType::Decimal256(5, 2));
+    test_round_trip(data)
+}
+
 #[test]
 fn utf8_nullable() -> Result<()> {
     let data = Utf8Array::<i32>::from(&vec![Some("a"), None, Some("bb"), None]);
@@ -220,6 +242,20 @@ fn schema() -> Result<()> {
     );
     test_round_trip_schema(field)?;
 
+    // the metadata of a dictionary field is exported on the field itself, not on its values
+    let mut metadata = BTreeMap::new();
+    metadata.insert("some".to_string(), "stuff".to_string());
+    let field = Field::new(
+        "a",
+        DataType::Dictionary(IntegerType::Int64, Box::new(DataType::LargeUtf8), true),
+        false,
+    )
+    .with_metadata(metadata);
+    test_round_trip_schema(field)?;
+
+    let field = Field::new("a", DataType::Decimal256(76, 2), true);
+    test_round_trip_schema(field)?;
+
     let field = Field::new("a", DataType::Int32, true);
     let mut metadata = BTreeMap::new();
     metadata.insert("some".to_string(), "stuff".to_string());
@@ -227,6 +263,22 @@ fn schema() -> Result<()> {
     test_round_trip_schema(field)
 }
 
+#[test]
+fn metadata_encoding() -> Result<()> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("a".to_string(), "bc".to_string());
+    metadata.insert("key".to_string(), "".to_string());
+    let field = Field::new("a", DataType::Int32, true).with_metadata(metadata);
+    test_round_trip_schema(field)
+}
+
+#[test]
+fn import_null_format() {
+    let schema = ffi::ArrowSchema::empty();
+    assert!(schema.validate().is_err());
+    assert!(unsafe { ffi::import_field_from_c(&schema) }.is_err());
+}
+
 #[test]
 fn extension() -> Result<()> {
     let field = Field::new(
@@ -236,3 +288,97 @@ fn extension() -> Result<()> {
     );
     test_round_trip_schema(field)
 }
+
+#[test]
+fn extension_with_metadata() -> Result<()> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("a".to_string(), "b".to_string());
+    metadata.insert("z".to_string(), "".to_string());
+
+    let inner = Field::new(
+        "a",
+        DataType::Extension(
+            "inner".to_string(),
+            Box::new(DataType::Int32),
+            Some("inner metadata".to_string()),
+        ),
+        true,
+    )
+    .with_metadata(metadata.clone());
+    let field = Field::new(
+        "b",
+        DataType::Extension(
+            "outer".to_string(),
+            Box::new(DataType::Struct(vec![
+                inner,
+                Field::new("c", DataType::Utf8, false),
+            ])),
+            Some("outer metadata".to_string()),
+        ),
+        false,
+    )
+    .with_metadata(metadata);
+    test_round_trip_schema(field)?;
+
+    let field = Field::new(
+        "a",
+        DataType::Extension(
+            "dict".to_string(),
+            Box::new(DataType::Dictionary(
+                IntegerType::Int32,
+                Box::new(DataType::Utf8),
+                true,
+            )),
+            None,
+        ),
+        true,
+    );
+    test_round_trip_schema(field)
+}
+
+#[test]
+fn chunk() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ];
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>,
+    ]);
+
+    let array_ptr = Box::into_raw(Box::new(ffi::ArrowArray::empty()));
+    let schema_ptr = Box::into_raw(Box::new(ffi::ArrowSchema::empty()));
+
+    unsafe { ffi::export_chunk_to_c(chunk.clone(), &fields, array_ptr, schema_ptr) };
+
+    let array_ptr = unsafe { Box::from_raw(array_ptr) };
+    let schema_ptr = unsafe { Box::from_raw(schema_ptr) };
+
+    let (result_fields, result_chunk) =
+        unsafe { ffi::import_chunk_from_c(array_ptr, schema_ptr.as_ref())? };
+
+    assert_eq!(result_fields, fields);
+    assert_eq!(result_chunk, chunk);
+    Ok(())
+}
+
+#[test]
+fn chunk_from_non_struct() -> Result<()> {
+    let array = Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>;
+    let field = Field::new("a", DataType::Int32, true);
+
+    let array_ptr = Box::into_raw(Box::new(ffi::ArrowArray::empty()));
+    let schema_ptr = Box::into_raw(Box::new(ffi::ArrowSchema::empty()));
+
+    unsafe {
+        ffi::export_array_to_c(array, array_ptr);
+        ffi::export_field_to_c(&field, schema_ptr);
+    }
+
+    let array_ptr = unsafe { Box::from_raw(array_ptr) };
+    let schema_ptr = unsafe { Box::from_raw(schema_ptr) };
+
+    assert!(unsafe { ffi::import_chunk_from_c(array_ptr, schema_ptr.as_ref()) }.is_err());
+    Ok(())
+}
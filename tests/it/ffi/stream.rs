@@ -1,7 +1,10 @@
+use std::cell::Cell;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use arrow2::array::*;
-use arrow2::datatypes::Field;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::{error::Result, ffi};
 
 fn _test_round_trip(arrays: Vec<Arc<dyn Array>>) -> Result<()> {
@@ -15,7 +18,7 @@ fn _test_round_trip(arrays: Vec<Arc<dyn Array>>) -> Result<()> {
     let mut stream = unsafe { ffi::ArrowArrayStreamReader::try_new(stream)? };
 
     let mut produced_arrays: Vec<Arc<dyn Array>> = vec![];
-    while let Some(array) = unsafe { stream.next() } {
+    for array in &mut stream {
         produced_arrays.push(array?.into());
     }
 
@@ -31,3 +34,44 @@ fn round_trip() -> Result<()> {
 
     _test_round_trip(vec![array.clone(), array.clone(), array])
 }
+
+#[test]
+fn writer_and_iterator() -> Result<()> {
+    let a: Arc<dyn Array> = Arc::new(Int32Array::from(&[Some(2), None, Some(1)]));
+    let b: Arc<dyn Array> = Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]));
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![a.clone(), b.clone()]);
+
+    // counts how many batches were pulled from the iterator
+    let pulled = Rc::new(Cell::new(0));
+    let counter = pulled.clone();
+    let batches = vec![chunk.clone(), chunk, Chunk::new(vec![a.clone()])];
+    let iter = batches.into_iter().map(move |chunk| {
+        counter.set(counter.get() + 1);
+        Ok(chunk)
+    });
+
+    let writer = ffi::ArrowArrayStreamWriter::new(schema.clone(), Box::new(iter));
+    let mut stream = Box::new(ffi::ArrowArrayStream::empty());
+    unsafe { writer.export(&mut *stream) }
+    assert_eq!(pulled.get(), 0);
+
+    let mut stream = unsafe { ffi::ArrowArrayStreamReader::try_new(stream)? };
+    let data_type = DataType::Struct(schema.fields.clone());
+    assert_eq!(stream.field().data_type(), &data_type);
+
+    let expected: Arc<dyn Array> = Arc::new(StructArray::new(data_type, vec![a, b], None));
+    let array: Arc<dyn Array> = stream.next().unwrap()?.into();
+    assert_eq!(array, expected);
+    assert_eq!(pulled.get(), 1);
+    let array: Arc<dyn Array> = stream.next().unwrap()?.into();
+    assert_eq!(array, expected);
+    assert_eq!(pulled.get(), 2);
+    // wrong number of columns
+    assert!(stream.next().unwrap().is_err());
+    assert!(stream.next().is_none());
+    Ok(())
+}
@@ -557,3 +557,222 @@ fn invalid_utf8() {
         error
     );
 }
+
+#[test]
+fn statistics_as_scalars() -> Result<()> {
+    if std::env::var("ARROW2_IGNORE_PARQUET").is_ok() {
+        return Ok(());
+    }
+    use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+    let mut file = File::open("fixtures/pyarrow3/v1/basic_nullable_10.parquet").unwrap();
+    let metadata = read_metadata(&mut file)?;
+    let schema = infer_schema(&metadata)?;
+
+    let field = schema.fields.iter().find(|f| f.name == "int64").unwrap();
+    let statistics = statistics::deserialize_scalars(field, &metadata.row_groups[0])?;
+    assert_eq!(
+        statistics.null_count.as_ref(),
+        &PrimitiveScalar::from(Some(3u64)) as &dyn arrow2::scalar::Scalar
+    );
+    assert_eq!(
+        statistics.min.as_ref(),
+        &PrimitiveScalar::from(Some(-256i64)) as &dyn arrow2::scalar::Scalar
+    );
+    assert_eq!(
+        statistics.max.as_ref(),
+        &PrimitiveScalar::from(Some(9i64)) as &dyn arrow2::scalar::Scalar
+    );
+
+    let field = schema.fields.iter().find(|f| f.name == "string").unwrap();
+    let statistics = statistics::deserialize_scalars(field, &metadata.row_groups[0])?;
+    assert_eq!(
+        statistics.max.as_ref(),
+        &Utf8Scalar::<i32>::new(Some("def")) as &dyn arrow2::scalar::Scalar
+    );
+    Ok(())
+}
+
+#[test]
+fn written_int32_statistics_as_scalars() -> Result<()> {
+    use arrow2::datatypes::{DataType, Schema};
+    use arrow2::scalar::{PrimitiveScalar, Scalar};
+
+    let field = Field::new("a", DataType::Int32, true);
+    let schema = Schema::from(vec![field.clone()]);
+    let array = Int32Array::from([Some(3), None, Some(-7), Some(12), None]);
+    let batches = vec![Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])];
+    let data = integration_write(&schema, &batches)?;
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let statistics = statistics::deserialize_scalars(&field, &metadata.row_groups[0])?;
+    assert_eq!(
+        statistics.null_count.as_ref(),
+        &PrimitiveScalar::from(Some(2u64)) as &dyn Scalar
+    );
+    assert_eq!(
+        statistics.min.as_ref(),
+        &PrimitiveScalar::from(Some(-7i32)) as &dyn Scalar
+    );
+    assert_eq!(
+        statistics.max.as_ref(),
+        &PrimitiveScalar::from(Some(12i32)) as &dyn Scalar
+    );
+    Ok(())
+}
+
+#[test]
+fn prune_row_groups_by_statistics() -> Result<()> {
+    use arrow2::datatypes::{DataType, Schema};
+    use arrow2::scalar::PrimitiveScalar;
+
+    let field = Field::new("a", DataType::Int64, true);
+    let schema = Schema::from(vec![field.clone()]);
+    let batches = vec![
+        Chunk::new(vec![
+            Arc::new(Int64Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>
+        ]),
+        Chunk::new(vec![
+            Arc::new(Int64Array::from([Some(10), Some(20), None])) as Arc<dyn Array>
+        ]),
+    ];
+    let data = integration_write(&schema, &batches)?;
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    // rows where `a > 5`
+    let predicate = |statistics: &statistics::ScalarStatistics| {
+        let max = statistics
+            .max
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<i64>>()
+            .unwrap();
+        max.value().map(|max| max > 5).unwrap_or(true)
+    };
+    let indices = prune_row_groups(&metadata.row_groups, &field, &predicate)?;
+    assert_eq!(indices, vec![1]);
+
+    // rows where `a is null`
+    let predicate = |statistics: &statistics::ScalarStatistics| {
+        let null_count = statistics
+            .null_count
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<u64>>()
+            .unwrap();
+        null_count.value().map(|count| count > 0).unwrap_or(true)
+    };
+    let indices = prune_row_groups(&metadata.row_groups, &field, &predicate)?;
+    assert_eq!(indices, vec![0, 1]);
+    Ok(())
+}
+
+#[test]
+fn nested_list_from_pages() -> Result<()> {
+    use arrow2::datatypes::{DataType, Schema};
+
+    let data = vec![
+        Some(vec![Some(1i32), None, Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4)]),
+        Some(vec![None, Some(6)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+
+    let field = Field::new("a", array.data_type().clone(), true);
+    assert!(matches!(field.data_type(), DataType::List(_)));
+    let schema = Schema::from(vec![field.clone()]);
+    let batches = vec![Chunk::new(vec![Arc::new(array.clone()) as Arc<dyn Array>])];
+    let data = integration_write(&schema, &batches)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+    let columns = read_columns(&mut reader, row_group.columns(), &field.name)?;
+
+    // deserialize the pages in chunks of 2 rows
+    let arrays = to_deserializer(columns, field, row_group.num_rows() as usize, Some(2))?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(arrays.len(), 3);
+
+    let expected = [array.slice(0, 2), array.slice(2, 2), array.slice(4, 1)];
+    for (array, expected) in arrays.iter().zip(expected.iter()) {
+        assert_eq!(array.as_ref(), expected as &dyn Array);
+    }
+    Ok(())
+}
+
+#[test]
+fn boolean_null_as() -> Result<()> {
+    use arrow2::datatypes::{DataType, Schema};
+
+    let array = BooleanArray::from([Some(true), None, Some(false), None]);
+    let field = Field::new("a", DataType::Boolean, true);
+    let schema = Schema::from(vec![field.clone()]);
+    let batches = vec![Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])];
+    let data = integration_write(&schema, &batches)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+
+    let read = |options: DecodeOptions| -> Result<Vec<BooleanArray>> {
+        let mut reader = reader.clone();
+        let (meta, chunk) = read_columns(&mut reader, row_group.columns(), &field.name)?
+            .pop()
+            .unwrap();
+        let pages = PageReader::new(Cursor::new(chunk), meta, Arc::new(|_, _| true), vec![]);
+        let pages = BasicDecompressor::new(pages, vec![]);
+        BooleanIter::new_with_options(pages, DataType::Boolean, usize::MAX, options).collect()
+    };
+
+    let arrays = read(DecodeOptions::default())?;
+    assert_eq!(
+        arrays,
+        vec![BooleanArray::from([Some(true), None, Some(false), None])]
+    );
+
+    let arrays = read(DecodeOptions {
+        null_as: Some(false),
+    })?;
+    assert_eq!(
+        arrays,
+        vec![BooleanArray::from_slice([true, false, false, false])]
+    );
+    assert_eq!(arrays[0].validity(), None);
+    Ok(())
+}
+
+#[test]
+fn max_bytes() -> Result<()> {
+    use arrow2::datatypes::{DataType, Schema};
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, false)]);
+    // two row groups of 5 rows each
+    let batches = vec![
+        Chunk::new(vec![
+            Arc::new(Int64Array::from_slice([0, 1, 2, 3, 4])) as Arc<dyn Array>
+        ]),
+        Chunk::new(vec![
+            Arc::new(Int64Array::from_slice([5, 6, 7, 8, 9])) as Arc<dyn Array>
+        ]),
+    ];
+    let data = integration_write(&schema, &batches)?;
+
+    let mut reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    // enough for the first row group, but not for the second one
+    let max_bytes = reader.metadata().row_groups[0].columns()[0].uncompressed_size() as usize;
+    reader.set_max_bytes(max_bytes);
+
+    let chunk = reader.next().unwrap()?;
+    assert_eq!(chunk.len(), 5);
+    assert_eq!(reader.yielded_bytes(), 40);
+    // the second row group is rejected before being read
+    assert!(reader.next().unwrap().is_err());
+    assert_eq!(reader.yielded_bytes(), 40);
+    assert!(reader.next().is_none());
+    Ok(())
+}
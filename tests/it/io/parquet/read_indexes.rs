@@ -1,6 +1,7 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
+use arrow2::bitmap::Bitmap;
 use arrow2::error::ArrowError;
 use arrow2::{array::*, datatypes::*, error::Result, io::parquet::read::*, io::parquet::write::*};
 use parquet2::indexes::{compute_rows, select_pages};
@@ -221,3 +222,135 @@ fn indexed_dict() -> Result<()> {
 
     read_with_indexes(pages(&[&array], Encoding::RleDictionary)?, expected)
 }
+
+/// Returns a file with a single row group of 3 pages of 100 rows each, `[0, 300)`
+fn three_pages() -> Result<(Vec<u8>, Schema)> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, false)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+    };
+
+    // 3 pages of 100 rows each
+    let pages = (0..3)
+        .map(|page| {
+            let array = Int64Array::from_vec((page * 100..(page + 1) * 100).collect());
+            array_to_page(
+                &array,
+                parquet_schema.columns()[0].descriptor.clone(),
+                options,
+                Encoding::Plain,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let pages = DynIter::new(pages.into_iter().map(Ok));
+    let pages = Compressor::new(pages, options.compression, vec![]).map_err(ArrowError::from);
+    let row_group = DynIter::new(std::iter::once(Ok(DynStreamingIterator::new(pages))));
+
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), options)?;
+    writer.start()?;
+    writer.write(row_group)?;
+    writer.end(None)?;
+    Ok((writer.into_inner(), schema))
+}
+
+#[test]
+fn read_row_ranges() -> Result<()> {
+    let (data, schema) = three_pages()?;
+    let mut reader = Cursor::new(data);
+
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+    let field = schema.fields[0].clone();
+
+    let arrays =
+        read_columns_with_row_ranges(&mut reader, row_group, field.clone(), &[100..110], None)?
+            .collect::<Result<Vec<_>>>()?;
+    let expected = Int64Array::from_vec((100..110).collect());
+    assert_eq!(arrays, vec![Arc::new(expected) as Arc<dyn Array>]);
+
+    // ranges spanning multiple pages
+    let arrays = read_columns_with_row_ranges(
+        &mut reader,
+        row_group,
+        field.clone(),
+        &[5..7, 195..205],
+        None,
+    )?
+    .collect::<Result<Vec<_>>>()?;
+    let expected = Int64Array::from_vec([5, 6].into_iter().chain(195..205).collect());
+    assert_eq!(arrays, vec![Arc::new(expected) as Arc<dyn Array>]);
+
+    // invalid ranges
+    assert!(
+        read_columns_with_row_ranges(&mut reader, row_group, field, &[10..20, 5..7], None).is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn read_row_mask() -> Result<()> {
+    let (data, schema) = three_pages()?;
+    let mut reader = Cursor::new(data);
+
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+    let field = schema.fields[0].clone();
+
+    // rows in the first and last page, but none of the second page
+    let mask = (0..300)
+        .map(|i| i % 7 == 0 && !(100..200).contains(&i))
+        .collect::<Bitmap>();
+
+    let arrays = read_columns_with_row_mask(&mut reader, row_group, field.clone(), &mask, None)?
+        .collect::<Result<Vec<_>>>()?;
+
+    // decode-then-filter
+    let all = read_columns_with_row_ranges(&mut reader, row_group, field.clone(), &[0..300], None)?
+        .collect::<Result<Vec<_>>>()?;
+    let all = all[0].as_any().downcast_ref::<Int64Array>().unwrap();
+    let expected = all
+        .values()
+        .iter()
+        .zip(mask.iter())
+        .filter_map(|(value, is_set)| is_set.then(|| *value))
+        .collect::<Vec<_>>();
+    let expected = Int64Array::from_vec(expected);
+    assert_eq!(arrays, vec![Arc::new(expected) as Arc<dyn Array>]);
+
+    // the mask must have one entry per row
+    let mask = Bitmap::from([true]);
+    assert!(read_columns_with_row_mask(&mut reader, row_group, field, &mask, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_row_mask_without_page_index() -> Result<()> {
+    // this file was written without an offset index
+    let path = "testing/parquet-testing/data/alltypes_plain.parquet";
+    let mut reader = std::fs::File::open(path)?;
+
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+    let row_group = &metadata.row_groups[0];
+    let field = schema.fields[0].clone();
+
+    let mask = Bitmap::from([true, false, false, true, true, false, true, false]);
+    let arrays = read_columns_with_row_mask(&mut reader, row_group, field.clone(), &mask, None)?
+        .collect::<Result<Vec<_>>>()?;
+    let expected = Int32Array::from_slice([4, 7, 2, 0]);
+    assert_eq!(arrays, vec![Arc::new(expected) as Arc<dyn Array>]);
+
+    // chunks are filtered individually
+    let arrays = read_columns_with_row_mask(&mut reader, row_group, field, &mask, Some(5))?
+        .collect::<Result<Vec<_>>>()?;
+    let expected = vec![
+        Arc::new(Int32Array::from_slice([4, 7, 2])) as Arc<dyn Array>,
+        Arc::new(Int32Array::from_slice([0])) as Arc<dyn Array>,
+    ];
+    assert_eq!(arrays, expected);
+    Ok(())
+}
@@ -542,3 +542,44 @@ fn decimal_26_required_v2() -> Result<()> {
         Encoding::Plain,
     )
 }
+
+#[test]
+fn bool_optional_v2_page() -> Result<()> {
+    use arrow2::io::parquet::read::{BooleanIter, DataPageHeader};
+
+    let array = BooleanArray::from([Some(true), None, Some(false), None, Some(true)]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Boolean, true)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+    };
+    let page = array_to_page(
+        &array,
+        parquet_schema.columns()[0].descriptor.clone(),
+        options,
+        Encoding::Plain,
+    )?;
+    let page = match page {
+        EncodedPage::Data(page) => page,
+        _ => unreachable!(),
+    };
+
+    // v2 pages store the (RLE-encoded) def levels in a separate buffer, without a length prefix
+    match page.header() {
+        DataPageHeader::V2(header) => {
+            assert_eq!(header.num_nulls, 2);
+            assert_eq!(header.repetition_levels_byte_length, 0);
+            assert!(header.definition_levels_byte_length > 0);
+        }
+        _ => panic!("expected a v2 data page"),
+    }
+
+    let pages = fallible_streaming_iterator::convert(vec![Ok(page)].into_iter());
+    let result =
+        BooleanIter::new(pages, DataType::Boolean, array.len()).collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, vec![array]);
+    Ok(())
+}
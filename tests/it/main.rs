@@ -1,6 +1,7 @@
 mod array;
 mod bitmap;
 mod buffer;
+mod datatypes;
 mod ffi;
 mod scalar;
 mod temporal_conversions;
@@ -26,3 +26,16 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn hash_and_partial_ord() {
+    use std::collections::HashSet;
+    let a = BooleanScalar::from(Some(false));
+    let b = BooleanScalar::from(Some(true));
+    let null = BooleanScalar::from(None);
+    assert!(a < b);
+    assert!(b < null);
+
+    let set = [a.clone(), b, null, a].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 3);
+}
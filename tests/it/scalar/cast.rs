@@ -0,0 +1,48 @@
+use arrow2::{
+    datatypes::DataType,
+    error::ArrowError,
+    scalar::{cast_scalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar},
+};
+
+#[test]
+fn numeric_to_numeric() {
+    let scalar = PrimitiveScalar::from(Some(1i32));
+    let result = cast_scalar(&scalar, &DataType::Float64).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &PrimitiveScalar::from(Some(1.0f64)) as &dyn Scalar
+    );
+
+    // overflows are null
+    let scalar = PrimitiveScalar::from(Some(256i32));
+    let result = cast_scalar(&scalar, &DataType::UInt8).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &PrimitiveScalar::<u8>::from(None) as &dyn Scalar
+    );
+}
+
+#[test]
+fn numeric_to_utf8() {
+    let scalar = PrimitiveScalar::from(Some(-10i64));
+    let result = cast_scalar(&scalar, &DataType::Utf8).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Scalar::<i32>::from(Some("-10")) as &dyn Scalar
+    );
+}
+
+#[test]
+fn null() {
+    let scalar = PrimitiveScalar::<i32>::from(None);
+    let result = cast_scalar(&scalar, &DataType::Int64).unwrap();
+    assert_eq!(result.data_type(), &DataType::Int64);
+    assert!(!result.is_valid());
+}
+
+#[test]
+fn not_implemented() {
+    let scalar = BooleanScalar::from(Some(true));
+    let result = cast_scalar(&scalar, &DataType::Struct(vec![]));
+    assert!(matches!(result, Err(ArrowError::NotYetImplemented(_))));
+}
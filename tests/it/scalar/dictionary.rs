@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, DictionaryArray, Utf8Array},
+    datatypes::{DataType, IntegerType},
+    scalar::{DictionaryScalar, Scalar, Utf8Scalar},
+};
+
+#[test]
+fn to_boxed_array_keeps_data_type() {
+    // an ordered dictionary
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), true);
+
+    let value = Arc::new(Utf8Scalar::<i32>::from(Some("a"))) as Arc<dyn Scalar>;
+    let scalar = DictionaryScalar::<i32>::new(data_type.clone(), Some(value));
+    let array = scalar.to_boxed_array(2);
+    assert_eq!(array.data_type(), &data_type);
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(
+        array.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a"]) as &dyn Array
+    );
+    assert_eq!(array.keys().values().as_slice(), &[0, 0]);
+
+    let scalar = DictionaryScalar::<i32>::new(data_type.clone(), None);
+    let array = scalar.to_boxed_array(2);
+    assert_eq!(array.data_type(), &data_type);
+    assert_eq!(array.null_count(), 2);
+}
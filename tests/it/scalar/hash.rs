@@ -0,0 +1,128 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, Float64Array, Int32Array},
+    datatypes::{DataType, Field},
+    scalar::*,
+};
+
+fn hash(scalar: &dyn Scalar) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hash_scalar(scalar, &mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn consistent_with_equal() {
+    let list_dt = DataType::List(Box::new(Field::new("a", DataType::Int32, true)));
+    let struct_dt = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let scalars: Vec<Box<dyn Scalar>> = vec![
+        Box::new(NullScalar::new()),
+        Box::new(BooleanScalar::from(Some(true))),
+        Box::new(PrimitiveScalar::from(Some(1.5f64))),
+        Box::new(PrimitiveScalar::from(Some(-0.0f32))),
+        Box::new(Utf8Scalar::<i32>::from(Some("a"))),
+        Box::new(BinaryScalar::<i64>::from(Some(b"a"))),
+        Box::new(FixedSizeBinaryScalar::new(
+            DataType::FixedSizeBinary(1),
+            Some(b"a".to_vec()),
+        )),
+        Box::new(ListScalar::<i32>::new(
+            list_dt.clone(),
+            Some(Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>),
+        )),
+        Box::new(ListScalar::<i32>::new(list_dt, None)),
+        Box::new(StructScalar::new(
+            struct_dt,
+            Some(vec![
+                Arc::new(PrimitiveScalar::from(Some(1i32))) as Arc<dyn Scalar>
+            ]),
+        )),
+    ];
+    for scalar in scalars {
+        let other = scalar.clone();
+        assert!(equal(scalar.as_ref(), other.as_ref()));
+        assert_eq!(hash(scalar.as_ref()), hash(other.as_ref()));
+    }
+}
+
+#[test]
+fn nulls() {
+    // a typed `NullScalar` equals any null scalar of the same type
+    let a = NullScalar::new_typed(DataType::Int32);
+    let b = PrimitiveScalar::<i32>::new(DataType::Int32, None);
+    assert!(equal(&a, &b));
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn nan() {
+    let list_dt = DataType::List(Box::new(Field::new("a", DataType::Float64, true)));
+    let scalars: Vec<Box<dyn Scalar>> = vec![
+        Box::new(PrimitiveScalar::from(Some(f64::NAN))),
+        Box::new(PrimitiveScalar::from(Some(f32::NAN))),
+        Box::new(ListScalar::<i32>::new(
+            list_dt,
+            Some(Arc::new(Float64Array::from([Some(f64::NAN), None])) as Arc<dyn Array>),
+        )),
+    ];
+    // `Eq` requires every scalar to be equal to itself
+    for scalar in scalars {
+        assert!(equal(scalar.as_ref(), scalar.as_ref()));
+        assert_eq!(&scalar, &scalar.clone());
+    }
+
+    let a = PrimitiveScalar::from(Some(f64::NAN));
+    let b = PrimitiveScalar::from(Some(-f64::NAN));
+    assert!(equal(&a, &b));
+    assert_eq!(hash(&a), hash(&b));
+
+    let mut map = HashMap::<Box<dyn Scalar>, usize>::new();
+    for value in [f64::NAN, -f64::NAN, 1.0] {
+        *map.entry(Box::new(PrimitiveScalar::from(Some(value))))
+            .or_default() += 1;
+    }
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&(Box::new(a) as Box<dyn Scalar>)], 2);
+}
+
+#[test]
+fn signed_zero() {
+    let a = PrimitiveScalar::from(Some(0.0f64));
+    let b = PrimitiveScalar::from(Some(-0.0f64));
+    assert!(!equal(&a, &b));
+}
+
+#[test]
+fn hash_map() {
+    let mut map = HashMap::<Box<dyn Scalar>, usize>::new();
+    let keys: Vec<Box<dyn Scalar>> = vec![
+        Box::new(Utf8Scalar::<i32>::from(Some("a"))),
+        Box::new(Utf8Scalar::<i32>::from(None::<&str>)),
+        Box::new(Utf8Scalar::<i32>::from(Some("a"))),
+        Box::new(Utf8Scalar::<i32>::from(Some("b"))),
+    ];
+    for key in keys {
+        *map.entry(key).or_default() += 1;
+    }
+    assert_eq!(map.len(), 3);
+    let a = Box::new(Utf8Scalar::<i32>::from(Some("a"))) as Box<dyn Scalar>;
+    assert_eq!(map[&a], 2);
+}
+
+#[test]
+fn dyn_hash_matches_concrete() {
+    let a = PrimitiveScalar::from(Some(1i32));
+    let mut hasher = DefaultHasher::new();
+    a.hash(&mut hasher);
+    assert_eq!(hash(&a), hasher.finish());
+}
+
+#[test]
+fn union() {
+    let scalar = super::UnionScalar::new();
+    assert_eq!(hash(&scalar), hash(&scalar.clone()));
+}
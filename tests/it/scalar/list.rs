@@ -40,3 +40,16 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn display() {
+    let dt = DataType::List(Box::new(Field::new("a", DataType::Boolean, true)));
+    let a: Box<dyn Scalar> = Box::new(ListScalar::<i32>::new(
+        dt.clone(),
+        Some(Arc::new(BooleanArray::from([Some(true), None, Some(false)])) as Arc<dyn Array>),
+    ));
+    assert_eq!(format!("{}", a), "[true, null, false]");
+
+    let a = ListScalar::<i32>::new(dt, None);
+    assert_eq!(format!("{}", a), "null");
+}
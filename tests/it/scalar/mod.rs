@@ -1,15 +1,208 @@
 mod binary;
 mod boolean;
+#[cfg(feature = "compute_cast")]
+mod cast;
+mod dictionary;
 mod fixed_size_binary;
 mod fixed_size_list;
+mod hash;
 mod list;
 mod null;
+mod ord;
 mod primitive;
+#[cfg(all(feature = "serde_types", feature = "io_json"))]
+mod serde;
 mod struct_;
 mod utf8;
 
+use arrow2::array::{new_null_array, Array};
+use arrow2::datatypes::{DataType, Field, UnionMode};
+use arrow2::scalar::Scalar;
+
+/// A valid [`Scalar`] of a type without a concrete scalar in arrow2 (a union).
+#[derive(Debug, Clone)]
+struct UnionScalar {
+    data_type: DataType,
+}
+
+impl UnionScalar {
+    fn new() -> Self {
+        let fields = vec![Field::new("a", DataType::Int32, true)];
+        Self {
+            data_type: DataType::Union(fields, None, UnionMode::Sparse),
+        }
+    }
+}
+
+impl Scalar for UnionScalar {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        true
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn clone_box(&self) -> Box<dyn Scalar> {
+        Box::new(self.clone())
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        new_null_array(self.data_type.clone(), length)
+    }
+}
+
 // check that `PartialEq` can be derived
 #[derive(PartialEq)]
 struct A {
     array: std::sync::Arc<dyn arrow2::scalar::Scalar>,
 }
+
+#[test]
+fn equal_dyn() {
+    use arrow2::scalar::{equal, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+
+    let a: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(1i32)));
+    let b: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(1i32)));
+    assert!(equal(a.as_ref(), b.as_ref()));
+
+    let b: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(2i32)));
+    assert!(!equal(a.as_ref(), b.as_ref()));
+
+    // different types are not equal
+    let b: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(1i64)));
+    assert!(!equal(a.as_ref(), b.as_ref()));
+    let b: Box<dyn Scalar> = Box::new(Utf8Scalar::<i32>::from(Some("1")));
+    assert!(!equal(a.as_ref(), b.as_ref()));
+    let b: Box<dyn Scalar> = Box::new(BooleanScalar::from(None));
+    assert!(!equal(a.as_ref(), b.as_ref()));
+}
+
+// check that `Clone` can be derived
+#[derive(Clone)]
+struct B {
+    scalar: Box<dyn arrow2::scalar::Scalar>,
+}
+
+#[test]
+fn clone_box() {
+    use arrow2::scalar::{PrimitiveScalar, Scalar, Utf8Scalar};
+
+    let a: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(1i32)));
+    assert_eq!(a.clone_box(), a);
+    assert_eq!(a.clone(), a);
+
+    let b = B {
+        scalar: Box::new(Utf8Scalar::<i32>::from(Some("a"))),
+    };
+    assert_eq!(b.clone().scalar, b.scalar);
+}
+
+#[test]
+fn to_array() {
+    use std::sync::Arc;
+
+    use arrow2::array::*;
+    use arrow2::datatypes::{DataType, Field};
+    use arrow2::scalar::{first_scalar, new_scalar, ListScalar, Scalar, StructScalar};
+
+    let arrays: Vec<Box<dyn Array>> = vec![
+        Box::new(NullArray::new(DataType::Null, 2)),
+        Box::new(BooleanArray::from([Some(true), None])),
+        Box::new(Int32Array::from([Some(1), None])),
+        Box::new(Utf8Array::<i64>::from([Some("a"), None])),
+        Box::new(BinaryArray::<i32>::from([Some(b"a"), None])),
+        Box::new(FixedSizeBinaryArray::from([Some([1u8, 2]), None])),
+    ];
+    for array in arrays {
+        for index in 0..array.len() {
+            let scalar = new_scalar(array.as_ref(), index);
+            assert_eq!(scalar.to_array().as_ref(), array.slice(index, 1).as_ref());
+
+            let repeated = scalar.to_boxed_array(3);
+            assert_eq!(repeated.len(), 3);
+            assert_eq!(repeated.data_type(), array.data_type());
+            (0..3).for_each(|i| assert_eq!(new_scalar(repeated.as_ref(), i), scalar));
+        }
+        assert_eq!(first_scalar(array.as_ref()), new_scalar(array.as_ref(), 0));
+    }
+
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let values = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let scalar = ListScalar::<i32>::new(data_type.clone(), Some(values));
+    let array = scalar.to_boxed_array(2);
+    let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 4]);
+    assert_eq!(new_scalar(array, 1).as_ref(), &scalar as &dyn Scalar);
+
+    let scalar = ListScalar::<i32>::new(data_type, None);
+    assert!(scalar.to_array().is_null(0));
+
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let values =
+        vec![Arc::new(arrow2::scalar::PrimitiveScalar::from(Some(1i32))) as Arc<dyn Scalar>];
+    let scalar = StructScalar::new(data_type.clone(), Some(values));
+    let array = scalar.to_boxed_array(2);
+    assert_eq!(
+        new_scalar(array.as_ref(), 1).as_ref(),
+        &scalar as &dyn Scalar
+    );
+    let scalar = StructScalar::new(data_type, None);
+    assert!(scalar.to_array().is_null(0));
+}
+
+#[test]
+fn display_union() {
+    let scalar = UnionScalar::new();
+    let scalar: &dyn Scalar = &scalar;
+    assert_eq!(
+        format!("{}", scalar),
+        format!("<{:?} scalar>", scalar.data_type())
+    );
+}
+
+#[test]
+fn equal_unsupported() {
+    use arrow2::scalar::equal;
+
+    let a = UnionScalar::new();
+    let b = UnionScalar::new();
+    assert!(!equal(&a, &b));
+    let (a, b): (&dyn Scalar, &dyn Scalar) = (&a, &b);
+    assert!(a != b);
+}
+
+/// A [`Scalar`] implementing only the required methods.
+#[derive(Debug)]
+struct Int32Scalar(Option<i32>);
+
+impl Scalar for Int32Scalar {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.is_some()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &DataType::Int32
+    }
+
+    fn to_boxed_array(&self, length: usize) -> Box<dyn Array> {
+        Box::new(arrow2::array::Int32Array::from(vec![self.0; length]))
+    }
+}
+
+#[test]
+fn clone_box_default() {
+    use arrow2::scalar::PrimitiveScalar;
+
+    let scalar = Int32Scalar(Some(1));
+    let expected: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(1i32)));
+    assert_eq!(scalar.clone_box(), expected);
+}
@@ -19,3 +19,37 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn display() {
+    let a: Box<dyn Scalar> = Box::new(NullScalar::new());
+    assert_eq!(format!("{}", a), "null");
+}
+
+#[test]
+fn typed() {
+    use arrow2::array::{Array, Int32Array};
+    use arrow2::scalar::PrimitiveScalar;
+
+    let a = NullScalar::new_typed(DataType::Int32);
+    assert_eq!(a.data_type(), &DataType::Int32);
+    assert!(!a.is_valid());
+    assert!(a != NullScalar::new());
+
+    let a: Box<dyn Scalar> = Box::new(a);
+    assert_eq!(format!("{}", a), "null");
+
+    // equal to nulls of the same type, but not to valid values
+    assert_eq!(
+        a,
+        Box::new(PrimitiveScalar::<i32>::from(None)) as Box<dyn Scalar>
+    );
+    assert!(a != Box::new(PrimitiveScalar::from(Some(1i32))) as Box<dyn Scalar>);
+    assert!(a != Box::new(NullScalar::new()) as Box<dyn Scalar>);
+
+    let array = a.to_boxed_array(2);
+    assert_eq!(
+        array.as_ref(),
+        &Int32Array::new_null(DataType::Int32, 2) as &dyn Array
+    );
+}
@@ -0,0 +1,69 @@
+use std::cmp::Ordering;
+
+use arrow2::scalar::{
+    ord::{cmp_scalar, compare, compare_with_nulls},
+    BooleanScalar, PrimitiveScalar, Utf8Scalar,
+};
+
+#[test]
+fn primitive() {
+    let a = PrimitiveScalar::from(Some(1i32));
+    let b = PrimitiveScalar::from(Some(2i32));
+    let null = PrimitiveScalar::<i32>::from(None);
+
+    assert_eq!(compare(&a, &b).unwrap(), Ordering::Less);
+    assert_eq!(compare(&b, &a).unwrap(), Ordering::Greater);
+    assert_eq!(compare(&a, &a).unwrap(), Ordering::Equal);
+    assert_eq!(compare(&null, &a).unwrap(), Ordering::Less);
+    assert_eq!(compare(&null, &null).unwrap(), Ordering::Equal);
+    assert_eq!(
+        compare_with_nulls(&null, &a, false).unwrap(),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn float() {
+    let a = PrimitiveScalar::from(Some(-1.0f64));
+    let b = PrimitiveScalar::from(Some(f64::NAN));
+    assert_eq!(compare(&a, &b).unwrap(), Ordering::Less);
+}
+
+#[test]
+fn utf8() {
+    let a = Utf8Scalar::<i32>::from(Some("a"));
+    let b = Utf8Scalar::<i32>::from(Some("b"));
+    let null = Utf8Scalar::<i32>::from(None::<&str>);
+
+    assert_eq!(compare(&a, &b).unwrap(), Ordering::Less);
+    assert_eq!(compare(&a, &null).unwrap(), Ordering::Greater);
+    assert_eq!(
+        compare_with_nulls(&a, &null, false).unwrap(),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn boolean() {
+    let a = BooleanScalar::from(Some(false));
+    let b = BooleanScalar::from(Some(true));
+    assert_eq!(compare(&a, &b).unwrap(), Ordering::Less);
+}
+
+#[test]
+fn different_types() {
+    let a = PrimitiveScalar::from(Some(1i32));
+    let b = PrimitiveScalar::from(Some(1i64));
+    assert!(compare(&a, &b).is_err());
+
+    let b = Utf8Scalar::<i32>::from(Some("a"));
+    assert!(compare(&a, &b).is_err());
+}
+
+#[test]
+fn cmp_scalar_nulls_last() {
+    let a = Utf8Scalar::<i32>::from(Some("a"));
+    let null = Utf8Scalar::<i32>::from(None::<&str>);
+    assert_eq!(cmp_scalar(&a, &null).unwrap(), Ordering::Less);
+    assert_eq!(cmp_scalar(&null, &null).unwrap(), Ordering::Equal);
+}
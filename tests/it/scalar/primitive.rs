@@ -1,6 +1,6 @@
 use arrow2::{
     datatypes::DataType,
-    scalar::{PrimitiveScalar, Scalar},
+    scalar::{float_total_eq, ord::cmp_scalar, PrimitiveScalar, Scalar},
 };
 
 #[allow(clippy::eq_op)]
@@ -16,6 +16,30 @@ fn equal() {
     assert_eq!(b, b);
 }
 
+#[allow(clippy::eq_op)]
+#[test]
+fn total_eq_nan() {
+    let a = PrimitiveScalar::from(Some(f64::NAN));
+    let b = PrimitiveScalar::from(Some(-f64::NAN));
+    assert!(a != a);
+    assert!(float_total_eq(&a, &a));
+    assert!(float_total_eq(&a, &b));
+    assert!(!float_total_eq(&a, &PrimitiveScalar::from(Some(1.0))));
+    assert!(!float_total_eq(&a, &PrimitiveScalar::from(None)));
+}
+
+#[test]
+fn total_eq_signed_zero() {
+    let a = PrimitiveScalar::from(Some(0.0f32));
+    let b = PrimitiveScalar::from(Some(-0.0f32));
+    assert_eq!(a, b);
+    assert!(!float_total_eq(&a, &b));
+    assert!(float_total_eq(&b, &b));
+
+    let null = PrimitiveScalar::<f32>::from(None);
+    assert!(float_total_eq(&null, &null));
+}
+
 #[test]
 fn basics() {
     let a = PrimitiveScalar::from(Some(2i32));
@@ -36,3 +60,111 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn hash() {
+    use std::collections::HashSet;
+    let mut set = HashSet::new();
+    set.insert(PrimitiveScalar::from(Some(1i32)));
+    set.insert(PrimitiveScalar::from(Some(1i32)));
+    set.insert(PrimitiveScalar::from(None::<i32>));
+    set.insert(PrimitiveScalar::from(None::<i32>));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn partial_ord() {
+    let a = PrimitiveScalar::from(Some(1i32));
+    let b = PrimitiveScalar::from(Some(2i32));
+    let null = PrimitiveScalar::<i32>::from(None);
+    assert!(a < b);
+    // nulls are last
+    assert!(b < null);
+
+    let mut values = vec![null.clone(), b.clone(), a.clone()];
+    values.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert_eq!(values, vec![a, b, null]);
+
+    // different logical types are not comparable
+    let c = PrimitiveScalar::from(Some(1i32)).to(DataType::Date32);
+    assert_eq!(PrimitiveScalar::from(Some(1i32)).partial_cmp(&c), None);
+}
+
+#[test]
+fn partial_ord_float() {
+    use std::cmp::Ordering;
+
+    let nan = PrimitiveScalar::from(Some(f64::NAN));
+    let one = PrimitiveScalar::from(Some(1.0f64));
+    let null = PrimitiveScalar::<f64>::from(None);
+    // floats are totally ordered: `NaN` is greater than any other value, but less than null
+    assert_eq!(one.partial_cmp(&nan), Some(Ordering::Less));
+    assert_eq!(nan.partial_cmp(&nan), Some(Ordering::Equal));
+    assert_eq!(nan.partial_cmp(&null), Some(Ordering::Less));
+    let zero = PrimitiveScalar::from(Some(0.0f32));
+    let negative_zero = PrimitiveScalar::from(Some(-0.0f32));
+    assert_eq!(negative_zero.partial_cmp(&zero), Some(Ordering::Less));
+
+    // consistent with `cmp_scalar`
+    for (lhs, rhs) in [(&one, &nan), (&nan, &one), (&nan, &null), (&one, &one)] {
+        assert_eq!(lhs.partial_cmp(rhs), cmp_scalar(lhs, rhs).ok());
+    }
+}
+
+#[test]
+fn display() {
+    let a = PrimitiveScalar::from(Some(2i32));
+    assert_eq!(format!("{}", a), "2");
+    let a: Box<dyn Scalar> = Box::new(PrimitiveScalar::<i32>::from(None));
+    assert_eq!(format!("{}", a), "null");
+}
+
+#[test]
+fn from_option() {
+    use arrow2::datatypes::IntervalUnit;
+    use arrow2::types::months_days_ns;
+
+    let a = PrimitiveScalar::from(Some(1u64));
+    assert_eq!(a.value(), Some(1u64));
+    assert_eq!(a.data_type(), &DataType::UInt64);
+
+    let a = PrimitiveScalar::from(Some(months_days_ns::new(1, 2, 3)));
+    assert_eq!(a.value(), Some(months_days_ns::new(1, 2, 3)));
+    assert_eq!(
+        a.data_type(),
+        &DataType::Interval(IntervalUnit::MonthDayNano)
+    );
+
+    let a = PrimitiveScalar::<f32>::from(None);
+    assert_eq!(a.value(), None);
+    assert_eq!(a.data_type(), &DataType::Float32);
+}
+
+#[test]
+fn decimal() {
+    let a = PrimitiveScalar::new(DataType::Decimal(5, 2), Some(12345i128));
+    assert_eq!(a.precision(), Some(5));
+    assert_eq!(a.scale(), Some(2));
+    assert_eq!(a.to_string_scaled(), Some("123.45".to_string()));
+
+    let cases = [
+        (-12345i128, 2, "-123.45"),
+        (5, 2, "0.05"),
+        (-5, 3, "-0.005"),
+        (100, 0, "100"),
+        (0, 2, "0.00"),
+    ];
+    for (value, scale, expected) in cases {
+        let a = PrimitiveScalar::new(DataType::Decimal(10, scale), Some(value));
+        assert_eq!(a.to_string_scaled(), Some(expected.to_string()));
+    }
+
+    let a = PrimitiveScalar::<i128>::new(DataType::Decimal(5, 2), None);
+    assert_eq!(a.scale(), Some(2));
+    assert_eq!(a.to_string_scaled(), None);
+
+    // the default logical type of `i128` is a decimal
+    let a = PrimitiveScalar::from(Some(1i128));
+    assert_eq!(a.precision(), Some(32));
+    assert_eq!(a.to_string_scaled(), Some(format!("0.{:0>32}", 1)));
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    datatypes::{DataType, Field},
+    scalar::*,
+};
+
+fn round_trip(scalar: Box<dyn Scalar>) {
+    let json = serde_json::to_string(&scalar).unwrap();
+    let result: Box<dyn Scalar> = serde_json::from_str(&json).unwrap();
+    assert_eq!(&scalar, &result);
+}
+
+#[test]
+fn primitive() {
+    round_trip(Box::new(PrimitiveScalar::from(Some(1i32))));
+    round_trip(Box::new(PrimitiveScalar::from(Some(u64::MAX))));
+    round_trip(Box::new(PrimitiveScalar::from(Some(-1.5f64))));
+    round_trip(Box::new(
+        PrimitiveScalar::from(Some(i128::MAX)).to(DataType::Decimal(38, 0)),
+    ));
+    round_trip(Box::new(PrimitiveScalar::<i32>::from(None)));
+
+    let scalar = PrimitiveScalar::from(Some(1i32));
+    let json = serde_json::to_string(&scalar).unwrap();
+    assert_eq!(json, r#"{"data_type":"Int32","value":1}"#);
+    let result: PrimitiveScalar<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(result, scalar);
+}
+
+#[test]
+fn utf8() {
+    round_trip(Box::new(Utf8Scalar::<i32>::new(Some("a"))));
+    round_trip(Box::new(Utf8Scalar::<i64>::new(None::<&str>)));
+
+    let json = serde_json::to_string(&Utf8Scalar::<i32>::new(None::<&str>)).unwrap();
+    assert_eq!(json, r#"{"data_type":"Utf8","value":null}"#);
+}
+
+#[test]
+fn list() {
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let values = Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    round_trip(Box::new(ListScalar::<i32>::new(
+        data_type.clone(),
+        Some(values),
+    )));
+    round_trip(Box::new(ListScalar::<i32>::new(
+        data_type.clone(),
+        Some(Arc::new(Int32Array::from_slice([])) as Arc<dyn Array>),
+    )));
+    round_trip(Box::new(ListScalar::<i32>::new(data_type, None)));
+}
+
+#[test]
+fn wrong_type() {
+    let json = serde_json::to_string(&Utf8Scalar::<i32>::new(Some("a"))).unwrap();
+    assert!(serde_json::from_str::<PrimitiveScalar<i32>>(&json).is_err());
+}
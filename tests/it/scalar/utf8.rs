@@ -31,3 +31,42 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn hash() {
+    use std::collections::HashMap;
+    let mut counts = HashMap::new();
+    for value in [Some("a"), None, Some("a"), Some("b"), None] {
+        *counts.entry(Utf8Scalar::<i32>::from(value)).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&Utf8Scalar::<i32>::from(Some("a"))], 2);
+    assert_eq!(counts[&Utf8Scalar::<i32>::from(None::<&str>)], 2);
+    assert_eq!(counts.len(), 3);
+}
+
+#[test]
+fn partial_ord() {
+    let a = Utf8Scalar::<i32>::from(Some("a"));
+    let b = Utf8Scalar::<i32>::from(Some("b"));
+    let null = Utf8Scalar::<i32>::from(None::<&str>);
+    assert!(a < b);
+    assert!(b < null);
+}
+
+#[test]
+fn display() {
+    let a: Box<dyn Scalar> = Box::new(Utf8Scalar::<i32>::from(Some("a")));
+    assert_eq!(format!("{}", a), "\"a\"");
+    let a = Utf8Scalar::<i64>::from(None::<&str>);
+    assert_eq!(format!("{}", a), "null");
+}
+
+#[test]
+fn from_string() {
+    let a = Utf8Scalar::<i32>::from_string(Some("a".to_string()));
+    assert_eq!(a, Utf8Scalar::<i32>::new(Some("a")));
+
+    let a = Utf8Scalar::<i64>::from_string(None);
+    assert_eq!(a, Utf8Scalar::<i64>::new(None::<&str>));
+    assert!(!a.is_valid());
+}